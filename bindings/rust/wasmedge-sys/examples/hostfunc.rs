@@ -11,9 +11,11 @@
 //! base on the inputs and outputs of the real host function.
 //!
 
-use wasmedge_sys::{Config, FuncType, Function, ImportObject, Loader, ValType, Vm, WasmValue};
+use wasmedge_sys::{
+    Caller, Config, FuncType, Function, ImportObject, Loader, ValType, Vm, WasmValue,
+};
 
-fn real_add(input: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+fn real_add(_caller: &Caller, input: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
     println!("Rust: Entering Rust function real_add");
 
     if input.len() != 3 {
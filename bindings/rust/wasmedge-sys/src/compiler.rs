@@ -4,7 +4,7 @@ use crate::{
     error::{check, WasmEdgeError},
     ffi, utils, Config, WasmEdgeResult,
 };
-use std::path::Path;
+use std::{path::Path, sync::mpsc, thread, time::Duration};
 
 /// Struct of WasmEdge ahead-of-time(AOT) compiler.
 #[derive(Debug)]
@@ -50,6 +50,12 @@ impl Compiler {
     ///
     /// - `out_path` specifies the output WASM file path.
     ///
+    /// # Notice
+    ///
+    /// The compilation blocks the current thread until it completes. The WasmEdge C API provides no way to cancel an
+    /// in-progress compilation, and the compilation is not interrupted even if the thread waiting on it gives up. Use
+    /// [Compiler::compile_with_timeout] to bound the time spent waiting on it.
+    ///
     /// # Error
     ///
     /// If fail to compile, then an error is returned.
@@ -68,6 +74,60 @@ impl Compiler {
             ))
         }
     }
+
+    /// Compiles the input WASM from the given file path for the AOT mode on a worker thread, and gives up waiting if
+    /// the compilation doesn't complete within the given timeout.
+    ///
+    /// # Arguments
+    ///
+    /// - `in_path` specifies the input WASM file path.
+    ///
+    /// - `out_path` specifies the output WASM file path.
+    ///
+    /// - `timeout` specifies the maximum time to wait for the compilation.
+    ///
+    /// # Error
+    ///
+    /// If the compilation doesn't complete within `timeout`, then a
+    /// [CompileTimeout](crate::error::WasmEdgeError::CompileTimeout) error is returned. If fail to compile, then an
+    /// error is returned.
+    ///
+    /// # Notice
+    ///
+    /// Since the WasmEdge C API provides no way to cancel an in-progress compilation, the worker thread, which owns the
+    /// [compiler](crate::Compiler), keeps compiling after a [CompileTimeout](crate::error::WasmEdgeError::CompileTimeout)
+    /// error is returned. The output file may therefore appear at `out_path` later, after this function has returned.
+    pub fn compile_with_timeout(
+        self,
+        in_path: impl AsRef<Path>,
+        out_path: impl AsRef<Path>,
+        timeout: Duration,
+    ) -> WasmEdgeResult<()> {
+        let in_path = in_path.as_ref().to_path_buf();
+        let out_path = out_path.as_ref().to_path_buf();
+        wait_with_timeout(timeout, move || self.compile(in_path, out_path))
+    }
+}
+
+/// Runs `compile` on a detached worker thread, and waits on its result for at most `timeout`.
+fn wait_with_timeout<F>(timeout: Duration, compile: F) -> WasmEdgeResult<()>
+where
+    F: FnOnce() -> WasmEdgeResult<()> + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel();
+    let handle = thread::spawn(move || {
+        // the receiver is gone if the caller has timed out
+        let _ = tx.send(compile());
+    });
+
+    match rx.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(mpsc::RecvTimeoutError::Timeout) => Err(WasmEdgeError::CompileTimeout(timeout)),
+        Err(mpsc::RecvTimeoutError::Disconnected) => match handle.join() {
+            Err(payload) => std::panic::resume_unwind(payload),
+            Ok(()) => unreachable!("the worker thread exits without sending the result"),
+        },
+    }
 }
 
 #[derive(Debug)]
@@ -156,6 +216,66 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_compiler_compile_with_timeout() {
+        let in_path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/test.wasm");
+        // a unique path, so that the concurrent test runs don't clobber each other
+        let out_path = std::env::temp_dir().join(format!(
+            "wasmedge_sys_test_compiler_timeout_{}_{}.wasm",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+
+        // compile within the timeout
+        let result = Compiler::create(None);
+        assert!(result.is_ok());
+        let compiler = result.unwrap();
+        let result = compiler.compile_with_timeout(&in_path, &out_path, Duration::from_secs(120));
+        assert!(result.is_ok());
+        assert!(out_path.exists());
+        assert!(std::fs::remove_file(out_path).is_ok());
+
+        // a compilation error is returned within the timeout
+        let result = Compiler::create(None);
+        assert!(result.is_ok());
+        let compiler = result.unwrap();
+        let result = compiler.compile_with_timeout(
+            "not_exist.wasm",
+            "not_exist_aot.wasm",
+            Duration::from_secs(120),
+        );
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Load(CoreLoadError::IllegalPath))
+        );
+    }
+
+    #[test]
+    fn test_compiler_wait_with_timeout() {
+        // the compilation is blocked until it is released
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let (done_tx, done_rx) = mpsc::channel();
+        let result = wait_with_timeout(Duration::from_millis(10), move || {
+            assert!(release_rx.recv().is_ok());
+            assert!(done_tx.send(()).is_ok());
+            Ok(())
+        });
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::CompileTimeout(Duration::from_millis(10))
+        );
+
+        // the detached compilation keeps running after the timeout
+        assert!(release_tx.send(()).is_ok());
+        assert!(done_rx.recv().is_ok());
+    }
+
     #[test]
     #[ignore]
     fn test_compiler_send() {
@@ -24,12 +24,26 @@ pub enum WasmEdgeError {
     StatisticsCreate,
     #[error("Fail to create Module")]
     ModuleCreate,
+    #[error("The size of the module exceeds the limit ({0} bytes)")]
+    ModuleTooLarge(usize),
+    #[error(
+        "The versioned module file was written by WasmEdge {found}, but the running version is {expected}"
+    )]
+    VersionMismatch { expected: String, found: String },
+    #[error("Invalid versioned module file: {0}")]
+    InvalidVersionedModule(String),
+    #[error("Fail to access the versioned module file: {0}")]
+    VersionedModuleIo(String),
+    #[error("The call to the host function ({func_name}) diverges from the recorded call {index}")]
+    ReplayDiverged { index: usize, func_name: String },
     #[error("Fail to create Loader")]
     LoaderCreate,
     #[error("Fail to create Config")]
     ConfigCreate,
     #[error("Fail to create AOT Compiler")]
     CompilerCreate,
+    #[error("The AOT compilation did not complete within {0:?}")]
+    CompileTimeout(std::time::Duration),
     #[error("Fail to create Validator")]
     ValidatorCreate,
     #[error("{0}")]
@@ -86,12 +100,46 @@ pub enum MemError {
     Create,
     #[error("Fail to get the memory type")]
     Type,
-    #[error("Fail to get the const pointer to data")]
-    ConstPtr,
-    #[error("Fail to get the pointer to data")]
-    MutPtr,
+    #[error("Fail to get the const pointer to data (offset: {offset}, len: {len}, memory size: {byte_len} bytes)")]
+    ConstPtr {
+        offset: u32,
+        len: u32,
+        byte_len: u64,
+    },
+    #[error("Fail to get the pointer to data (offset: {offset}, len: {len}, memory size: {byte_len} bytes)")]
+    MutPtr {
+        offset: u32,
+        len: u32,
+        byte_len: u64,
+    },
+    #[error(
+        "Memory access out of bounds (offset: {offset}, len: {len}, memory size: {byte_len} bytes)"
+    )]
+    OutOfBounds {
+        offset: u32,
+        len: u32,
+        byte_len: u64,
+    },
+    #[error("The length ({len} bytes) starting from the offset {offset} exceeds the 32-bit address space")]
+    LengthOverflow { offset: u32, len: u64 },
+    #[error("The required size ({0} bytes) exceeds the 32-bit address space")]
+    CapacityOverflow(u64),
     #[error("Fail to convert a raw pointer to a reference")]
     Ptr2Ref,
+    #[error("The number of bytes to read should be in the range [1, 8], but found {0}")]
+    InvalidByteWidth(u8),
+    #[error("The address of the region is not aligned to the element type")]
+    Unaligned,
+    #[error("Fail to compress the memory data: {0}")]
+    Compress(String),
+    #[error("Fail to decompress the memory data: {0}")]
+    Decompress(String),
+    #[error("The chunk size should be larger than zero")]
+    ZeroChunkSize,
+    #[error("Fail to write the memory data to the sink: {0}")]
+    Io(String),
+    #[error("Found an unpaired surrogate ({0:#06x}) in the UTF-16 string")]
+    InvalidUtf16(u16),
 }
 
 /// Defines the errors raised from [Global](crate::Global).
@@ -114,6 +162,10 @@ pub enum TableError {
     Create,
     #[error("Fail to get the table type")]
     Type,
+    #[error("The type of the element does not match the element type of the table")]
+    UnmatchedRefType,
+    #[error("The number of the elements exceeds the maximum size of the table")]
+    ElemsExceedMax,
 }
 
 /// Defines the errors raised from [Import](crate::Import).
@@ -163,6 +215,14 @@ pub enum InstanceError {
     NotFoundMem(String),
     #[error("Fail to find the target global ({0})")]
     NotFoundGlobal(String),
+    #[error("The export ({0}) is neither an integer global nor a function without parameters returning an integer")]
+    InvalidAbiVersion(String),
+    #[error("The function ({0}) should return a pointer and a length of the i32 type")]
+    UnmatchedBufferReturns(String),
+    #[error("Fail to allocate {0} bytes of scratch memory in the guest")]
+    ScratchAlloc(u32),
+    #[error("The active module instance has no name to attach a host state to")]
+    UnnamedHostState,
 }
 
 /// Defines the errors raised from [Store](crate::Store).
@@ -196,6 +256,13 @@ pub enum StoreError {
     NotFoundModule(String),
     #[error("Not found the active module")]
     NotFoundActiveModule,
+    #[error("Fail to read the directory ({0})")]
+    ReadDir(String),
+    #[error("Fail to register {} module(s) from the directory", failed.len())]
+    RegisterDir {
+        registered: Vec<String>,
+        failed: Vec<(std::path::PathBuf, WasmEdgeError)>,
+    },
 }
 
 /// Defines the errors raised from [Vm](crate::Vm).
@@ -225,6 +292,8 @@ pub enum VmError {
     NotFoundValidator,
     #[error("Fail to get Executor context")]
     NotFoundExecutor,
+    #[error("Fail to create a Vm pool without Vms or with a zero concurrency limit")]
+    EmptyPool,
 }
 
 /// Defines the errors raised from WasmEdge Core.
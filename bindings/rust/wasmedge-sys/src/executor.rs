@@ -3,18 +3,30 @@
 use super::ffi;
 use crate::{
     error::{check, WasmEdgeError},
+    statistics::Records,
     types::WasmEdgeString,
     Config, ImportObject, Module, Statistics, Store, WasmEdgeResult, WasmValue,
 };
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
 
 /// Struct of WasmEdge Executor.
 ///
 /// [Executor] defines an execution environment for both WASM and compiled WASM. It works with the
 /// [Store](crate::Store).
+///
+/// The [statistics](crate::Statistics) and its cost table are attached to an [Executor] rather than to a module. To
+/// meter the modules with different cost tables, create an [Executor] with its own [statistics](crate::Statistics)
+/// for each module, and then register and invoke each module through its [Executor]. The executors can share the
+/// same [Store](crate::Store). Notice that the cost is counted by the [Executor] which runs the invocation, including
+/// the cost of the functions called across the modules.
 #[derive(Debug)]
 pub struct Executor {
     pub(crate) inner: InnerExecutor,
     pub(crate) registered: bool,
+    records: Option<Arc<Mutex<Records>>>,
 }
 impl Executor {
     /// Creates a new [executor](crate::Executor) to be associated with the given [config](crate::Config) and [statistics](crate::Statistics).
@@ -29,6 +41,7 @@ impl Executor {
     ///
     /// If fail to create a [executor](crate::Executor), then an error is returned.
     pub fn create(config: Option<Config>, stat: Option<&mut Statistics>) -> WasmEdgeResult<Self> {
+        let records = stat.as_ref().map(|stat| Arc::clone(&stat.records));
         let ctx = match config {
             Some(mut config) => match stat {
                 Some(stat) => {
@@ -59,10 +72,31 @@ impl Executor {
             false => Ok(Executor {
                 inner: InnerExecutor(ctx),
                 registered: false,
+                records,
             }),
         }
     }
 
+    /// Adds the given elapsed time to the records of the [statistics](crate::Statistics), if any.
+    fn record_time(&self, elapsed: Duration, f: impl FnOnce(&mut Records) -> &mut Duration) {
+        if let Some(records) = &self.records {
+            let mut records = records.lock().expect("[wasmedge-sys] try lock failed.");
+            *f(&mut records) += elapsed;
+        }
+    }
+
+    /// Updates the peak memory size in the records of the [statistics](crate::Statistics), if any, with the size of
+    /// the memories of the invoked module, if the tracking is enabled by
+    /// [Statistics::track_memory](crate::Statistics::track_memory).
+    fn record_memory_pages(&self, store: &Store, mod_name: Option<&str>) {
+        if let Some(records) = &self.records {
+            let mut records = records.lock().expect("[wasmedge-sys] try lock failed.");
+            if records.track_memory {
+                records.record_memory_pages(store.max_mem_pages(mod_name));
+            }
+        }
+    }
+
     /// Registers and instantiates a WasmEdge [import object](crate::ImportObject) into a [store](crate::Store).
     ///
     /// # Arguments
@@ -79,14 +113,16 @@ impl Executor {
         store: &mut Store,
         import: &ImportObject,
     ) -> WasmEdgeResult<()> {
-        unsafe {
+        let start = Instant::now();
+        let result = unsafe {
             check(ffi::WasmEdge_ExecutorRegisterImport(
                 self.inner.0,
                 store.inner.0,
                 import.inner.0 as *const _,
-            ))?;
-        }
-        Ok(())
+            ))
+        };
+        self.record_time(start.elapsed(), |records| &mut records.instantiate);
+        result
     }
 
     /// Registers and instantiates a WasmEdge [module](crate::Module) into a store.
@@ -111,15 +147,17 @@ impl Executor {
         mod_name: impl AsRef<str>,
     ) -> WasmEdgeResult<()> {
         let mod_name: WasmEdgeString = mod_name.as_ref().into();
-        unsafe {
+        let start = Instant::now();
+        let result = unsafe {
             check(ffi::WasmEdge_ExecutorRegisterModule(
                 self.inner.0,
                 store.inner.0,
                 module.inner.0 as *const _,
                 mod_name.as_raw(),
-            ))?;
-        }
-        Ok(())
+            ))
+        };
+        self.record_time(start.elapsed(), |records| &mut records.instantiate);
+        result
     }
 
     /// Registers and instantiates a WasmEdge [module](crate::Module) into a [store](crate::Store) as an anonymous module.
@@ -142,14 +180,16 @@ impl Executor {
         store: &mut Store,
         module: &Module,
     ) -> WasmEdgeResult<()> {
-        unsafe {
+        let start = Instant::now();
+        let result = unsafe {
             check(ffi::WasmEdge_ExecutorInstantiate(
                 self.inner.0,
                 store.inner.0,
                 module.inner.0,
-            ))?;
-        }
-        Ok(())
+            ))
+        };
+        self.record_time(start.elapsed(), |records| &mut records.instantiate);
+        result
     }
 
     /// Invokes a WASM function in the anonymous [module](crate::Module), and returns the results.
@@ -175,6 +215,33 @@ impl Executor {
         func_name: impl AsRef<str>,
         params: impl IntoIterator<Item = WasmValue>,
     ) -> WasmEdgeResult<Vec<WasmValue>> {
+        let (returns, _) = self.run_func_timed(store, func_name, params)?;
+        Ok(returns)
+    }
+
+    /// Invokes a WASM function in the anonymous [module](crate::Module), and returns the results and the wall-clock
+    /// time spent in the invocation.
+    ///
+    /// The time is measured around the invocation only, so preparing the arguments and converting the results are
+    /// not included.
+    ///
+    /// # Arguments
+    ///
+    /// - `store` specifies the target [store](crate::Store) which owns the target function specified by `func_name`.
+    ///
+    /// - `func_name` specifies the name of the target function, which is stored in an anonymous module in `store`.
+    ///
+    /// - `params` specifies the argument values for the target function.
+    ///
+    /// # Error
+    ///
+    /// If fail to invoke the function specified by `func_name`, then an error is returned.
+    pub fn run_func_timed(
+        &mut self,
+        store: &mut Store,
+        func_name: impl AsRef<str>,
+        params: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<(Vec<WasmValue>, Duration)> {
         store.contains_func(func_name.as_ref())?;
 
         let raw_params = params.into_iter().map(|x| x.as_raw()).collect::<Vec<_>>();
@@ -184,7 +251,8 @@ impl Executor {
         let mut returns = Vec::with_capacity(returns_len);
 
         let func_name: WasmEdgeString = func_name.as_ref().into();
-        unsafe {
+        let start = Instant::now();
+        let result = unsafe {
             check(ffi::WasmEdge_ExecutorInvoke(
                 self.inner.0,
                 store.inner.0,
@@ -193,11 +261,18 @@ impl Executor {
                 raw_params.len() as u32,
                 returns.as_mut_ptr(),
                 returns_len as u32,
-            ))?;
-            returns.set_len(returns_len);
-        }
-
-        Ok(returns.into_iter().map(Into::into).collect::<Vec<_>>())
+            ))
+        };
+        let elapsed = start.elapsed();
+        self.record_time(elapsed, |records| &mut records.invoke);
+        self.record_memory_pages(store, None);
+        result?;
+        unsafe { returns.set_len(returns_len) };
+
+        Ok((
+            returns.into_iter().map(Into::into).collect::<Vec<_>>(),
+            elapsed,
+        ))
     }
 
     /// Invokes a registered WASM function by its module name and function name, and returns the results.
@@ -234,21 +309,25 @@ impl Executor {
             .returns_len();
         let mut returns = Vec::with_capacity(returns_len);
 
-        let mod_name: WasmEdgeString = mod_name.as_ref().into();
+        let raw_mod_name: WasmEdgeString = mod_name.as_ref().into();
         let func_name: WasmEdgeString = func_name.as_ref().into();
-        unsafe {
+        let start = Instant::now();
+        let result = unsafe {
             check(ffi::WasmEdge_ExecutorInvokeRegistered(
                 self.inner.0,
                 store.inner.0,
-                mod_name.as_raw(),
+                raw_mod_name.as_raw(),
                 func_name.as_raw(),
                 raw_params.as_ptr(),
                 raw_params.len() as u32,
                 returns.as_mut_ptr(),
                 returns_len as u32,
-            ))?;
-            returns.set_len(returns_len);
-        }
+            ))
+        };
+        self.record_time(start.elapsed(), |records| &mut records.invoke);
+        self.record_memory_pages(store, Some(mod_name.as_ref()));
+        result?;
+        unsafe { returns.set_len(returns_len) };
 
         Ok(returns.into_iter().map(Into::into).collect::<Vec<_>>())
     }
@@ -270,8 +349,8 @@ unsafe impl Sync for InnerExecutor {}
 mod tests {
     use super::*;
     use crate::{
-        Config, FuncType, Function, Global, GlobalType, MemType, Memory, Mutability, RefType,
-        Statistics, Table, TableType, ValType,
+        Caller, Config, FuncType, Function, Global, GlobalType, Loader, MemType, Memory,
+        Mutability, RefType, Statistics, Table, TableType, ValType, Validator,
     };
     use std::{
         sync::{Arc, Mutex},
@@ -417,6 +496,87 @@ mod tests {
         handle.join().unwrap();
     }
 
+    #[test]
+    fn test_executor_run_func_timed() {
+        // load and validate a module
+        let result = Loader::create(None);
+        assert!(result.is_ok());
+        let loader = result.unwrap();
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/fibonacci.wasm");
+        let result = loader.from_file(path);
+        assert!(result.is_ok());
+        let module = result.unwrap();
+        let result = Validator::create(None);
+        assert!(result.is_ok());
+        let validator = result.unwrap();
+        let result = validator.validate(&module);
+        assert!(result.is_ok());
+
+        // register the module as the active module
+        let result = Executor::create(None, None);
+        assert!(result.is_ok());
+        let mut executor = result.unwrap();
+        let result = Store::create();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+        let result = executor.register_active_module(&mut store, &module);
+        assert!(result.is_ok());
+
+        // run the function with and without timing
+        let result = executor.run_func(&mut store, "fib", [WasmValue::from_i32(10)]);
+        assert!(result.is_ok());
+        let returns = result.unwrap();
+        let result = executor.run_func_timed(&mut store, "fib", [WasmValue::from_i32(10)]);
+        assert!(result.is_ok());
+        let (returns_timed, elapsed) = result.unwrap();
+        assert_eq!(returns_timed.len(), 1);
+        assert_eq!(returns_timed[0].to_i32(), returns[0].to_i32());
+        assert!(elapsed > Duration::ZERO);
+
+        // run a function which does not exist
+        let result = executor.run_func_timed(&mut store, "not_exist", []);
+        assert!(result.is_err());
+
+        // register a host function which sleeps for 100 milliseconds
+        let result = ImportObject::create("extern");
+        assert!(result.is_ok());
+        let mut import_obj = result.unwrap();
+        let result = FuncType::create([], []);
+        assert!(result.is_ok());
+        let func_ty = result.unwrap();
+        let result = Function::create(&func_ty, Box::new(real_sleep), 0);
+        assert!(result.is_ok());
+        import_obj.add_func("sleep", result.unwrap());
+        let result = executor.register_import_object(&mut store, &import_obj);
+        assert!(result.is_ok());
+
+        // (module
+        //   (import "extern" "sleep" (func $sleep))
+        //   (func (export "run")
+        //     call $sleep))
+        let wasm: &[u8] = &[
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x04, 0x01, 0x60, 0x00, 0x00,
+            0x02, 0x10, 0x01, 0x06, 0x65, 0x78, 0x74, 0x65, 0x72, 0x6e, 0x05, 0x73, 0x6c, 0x65,
+            0x65, 0x70, 0x00, 0x00, 0x03, 0x02, 0x01, 0x00, 0x07, 0x07, 0x01, 0x03, 0x72, 0x75,
+            0x6e, 0x00, 0x01, 0x0a, 0x06, 0x01, 0x04, 0x00, 0x10, 0x00, 0x0b,
+        ];
+        let result = loader.from_buffer(wasm);
+        assert!(result.is_ok());
+        let module = result.unwrap();
+        let result = validator.validate(&module);
+        assert!(result.is_ok());
+        let result = executor.register_active_module(&mut store, &module);
+        assert!(result.is_ok());
+
+        // the elapsed time covers the host function
+        let result = executor.run_func_timed(&mut store, "run", []);
+        assert!(result.is_ok());
+        let (returns, elapsed) = result.unwrap();
+        assert!(returns.is_empty());
+        assert!(elapsed >= Duration::from_millis(100));
+    }
+
     #[test]
     fn test_executor_send() {
         // create an Executor context with the given configuration and statistics.
@@ -468,7 +628,12 @@ mod tests {
         handle.join().unwrap();
     }
 
-    fn real_add(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+    fn real_sleep(_caller: &Caller, _inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+        thread::sleep(Duration::from_millis(100));
+        Ok(vec![])
+    }
+
+    fn real_add(_caller: &Caller, inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
         if inputs.len() != 2 {
             return Err(1);
         }
@@ -6,8 +6,9 @@ use crate::{
     instance::{Function, Global, Memory, Table},
     types::WasmEdgeString,
     utils::string_to_c_char,
-    WasmEdgeResult,
+    Caller, FuncType, HostFunc, ValType, WasmEdgeResult, WasmValue,
 };
+use std::sync::{Arc, Mutex};
 
 /// Struct of WasmEdge ImportObject.
 ///
@@ -55,6 +56,20 @@ impl ImportObject {
     ///
     /// - `preopens` specifies the directories to pre-open. The required format is `DIR1:DIR2`.
     ///
+    /// # Notice
+    ///
+    /// The pre-opened directories must exist on the host filesystem. The WASI implementation of WasmEdge does not
+    /// provide a hook for a custom or in-memory virtual filesystem, so the guest can only access the host files
+    /// through the directories specified in `preopens`.
+    ///
+    /// In addition, the WASI clocks, such as the ones read by `clock_time_get`, always report the time of the host.
+    /// They cannot be replaced with a fixed or controllable time source, so a guest reading the clock is not
+    /// deterministic.
+    ///
+    /// The WASI host module binds the file descriptors 0, 1, and 2 of the guest to the file descriptors 0, 1, and 2 of
+    /// the host process, whatever they refer to when the guest accesses them. This function provides no way to bind
+    /// them to other files or buffers.
+    ///
     /// # Error
     ///
     /// If fail to create a host module, then an error is returned.
@@ -111,7 +126,8 @@ impl ImportObject {
     ///
     /// - `envs` specifies the environment variables in the format `ENV_VAR_NAME=VALUE`.
     ///
-    /// - `preopens` specifies the directories to pre-open. The required format is `DIR1:DIR2`.
+    /// - `preopens` specifies the directories to pre-open. The required format is `DIR1:DIR2`. See
+    /// [create_wasi](crate::ImportObject::create_wasi) for the limitations on the pre-opened directories.
     pub fn init_wasi(
         &mut self,
         args: Option<Vec<&str>>,
@@ -290,12 +306,317 @@ pub(crate) struct InnerImportObject(pub(crate) *mut ffi::WasmEdge_ImportObjectCo
 unsafe impl Send for InnerImportObject {}
 unsafe impl Sync for InnerImportObject {}
 
+/// Struct of WasmEdge RecordingImportObject.
+///
+/// A [RecordingImportObject] is a host module which records the arguments and the returns of each call to its host
+/// functions into a [HostTrace]. The recorded calls can be served later by a [ReplayImportObject] to reproduce a run
+/// deterministically.
+///
+/// # Notice
+///
+/// Only the numeric values can be recorded. A call with a reference value in its arguments or returns fails with a
+/// runtime error.
+#[derive(Debug)]
+pub struct RecordingImportObject {
+    import: ImportObject,
+    trace: HostTrace,
+}
+impl RecordingImportObject {
+    /// Creates a new recording host module with the given name.
+    ///
+    /// # Argument
+    ///
+    /// `name` specifies the name of the new host module.
+    ///
+    /// # Error
+    ///
+    /// If fail to create a host module, then an error is returned.
+    pub fn create(name: impl AsRef<str>) -> WasmEdgeResult<Self> {
+        Ok(Self {
+            import: ImportObject::create(name)?,
+            trace: HostTrace::default(),
+        })
+    }
+
+    /// Adds a [host function](crate::Function) of which the calls are recorded into the host module.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` specifies the name of the host function in the host module.
+    ///
+    /// - `ty` specifies the type of the host function.
+    ///
+    /// - `real_fn` specifies the host function to call and record.
+    ///
+    /// - `cost` specifies the cost of the host function.
+    ///
+    /// # Error
+    ///
+    /// If fail to create the host function, then an error is returned.
+    pub fn add_func(
+        &mut self,
+        name: impl AsRef<str>,
+        ty: &FuncType,
+        real_fn: HostFunc,
+        cost: u64,
+    ) -> WasmEdgeResult<()> {
+        let func_name = name.as_ref().to_string();
+        let trace = self.trace.clone();
+        let recording_fn = move |caller: &Caller, inputs: Vec<WasmValue>| {
+            let args = TraceValue::from_values(&inputs).ok_or(RUNTIME_ERROR)?;
+            let returns = real_fn(caller, inputs);
+            let recorded = match &returns {
+                Ok(returns) => Ok(TraceValue::from_values(returns).ok_or(RUNTIME_ERROR)?),
+                Err(code) => Err(*code),
+            };
+            trace.lock().calls.push(HostCall {
+                func_name: func_name.clone(),
+                args,
+                returns: recorded,
+            });
+            returns
+        };
+        let func = Function::create(ty, Box::new(recording_fn), cost)?;
+        self.import.add_func(name, func);
+        Ok(())
+    }
+
+    /// Returns the [HostTrace] which the calls are recorded into.
+    pub fn trace(&self) -> HostTrace {
+        self.trace.clone()
+    }
+
+    /// Returns the underlying [ImportObject](crate::ImportObject), which can be registered into a [Vm](crate::Vm).
+    pub fn into_import_object(self) -> ImportObject {
+        self.import
+    }
+}
+
+/// Struct of WasmEdge ReplayImportObject.
+///
+/// A [ReplayImportObject] is a host module which serves the returns recorded by a [RecordingImportObject] instead of
+/// calling the real host functions. Each call must match the next recorded call, in both the function name and the
+/// arguments. Otherwise, the call fails with a runtime error, and the divergence is reported by
+/// [HostTrace::check].
+#[derive(Debug)]
+pub struct ReplayImportObject {
+    import: ImportObject,
+    trace: HostTrace,
+}
+impl ReplayImportObject {
+    /// Creates a new replaying host module with the given name and recorded calls.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` specifies the name of the new host module.
+    ///
+    /// - `calls` specifies the recorded calls to serve in order.
+    ///
+    /// # Error
+    ///
+    /// If fail to create a host module, then an error is returned.
+    pub fn create(name: impl AsRef<str>, calls: Vec<HostCall>) -> WasmEdgeResult<Self> {
+        let trace = HostTrace::default();
+        trace.lock().calls = calls;
+        Ok(Self {
+            import: ImportObject::create(name)?,
+            trace,
+        })
+    }
+
+    /// Adds a [host function](crate::Function) which serves the recorded returns into the host module.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` specifies the name of the host function in the host module.
+    ///
+    /// - `ty` specifies the type of the host function.
+    ///
+    /// - `cost` specifies the cost of the host function.
+    ///
+    /// # Error
+    ///
+    /// If fail to create the host function, then an error is returned.
+    pub fn add_func(
+        &mut self,
+        name: impl AsRef<str>,
+        ty: &FuncType,
+        cost: u64,
+    ) -> WasmEdgeResult<()> {
+        let func_name = name.as_ref().to_string();
+        let trace = self.trace.clone();
+        let replay_fn = move |_caller: &Caller, inputs: Vec<WasmValue>| {
+            let mut state = trace.lock();
+            if state.divergence.is_some() {
+                return Err(RUNTIME_ERROR);
+            }
+
+            let index = state.cursor;
+            let matched = match (state.calls.get(index), TraceValue::from_values(&inputs)) {
+                (Some(call), Some(args)) if call.func_name == func_name && call.matches(&args) => {
+                    Some(call.returns.clone())
+                }
+                _ => None,
+            };
+            match matched {
+                Some(returns) => {
+                    state.cursor += 1;
+                    returns.map(|returns| returns.into_iter().map(TraceValue::to_value).collect())
+                }
+                None => {
+                    state.divergence = Some((index, func_name.clone()));
+                    Err(RUNTIME_ERROR)
+                }
+            }
+        };
+        let func = Function::create(ty, Box::new(replay_fn), cost)?;
+        self.import.add_func(name, func);
+        Ok(())
+    }
+
+    /// Returns the [HostTrace] which the recorded calls are served from.
+    pub fn trace(&self) -> HostTrace {
+        self.trace.clone()
+    }
+
+    /// Returns the underlying [ImportObject](crate::ImportObject), which can be registered into a [Vm](crate::Vm).
+    pub fn into_import_object(self) -> ImportObject {
+        self.import
+    }
+}
+
+/// The error code returned by the recording and replaying host functions on failure.
+const RUNTIME_ERROR: u8 = 0x02;
+
+/// Struct of a trace of the calls to the host functions.
+///
+/// A [HostTrace] is a handle shared with the [RecordingImportObject] or the [ReplayImportObject] it is returned by,
+/// so it can be inspected after the host module is registered.
+#[derive(Debug, Clone, Default)]
+pub struct HostTrace {
+    inner: Arc<Mutex<TraceState>>,
+}
+impl HostTrace {
+    /// Returns the recorded calls.
+    ///
+    /// With the `serde` feature enabled, the calls can be serialized to be replayed in another process.
+    pub fn calls(&self) -> Vec<HostCall> {
+        self.lock().calls.clone()
+    }
+
+    /// Returns the number of the recorded calls not served by the [ReplayImportObject] yet.
+    pub fn remaining(&self) -> usize {
+        let state = self.lock();
+        state.calls.len() - state.cursor
+    }
+
+    /// Checks if the calls to the [ReplayImportObject] match the recorded calls.
+    ///
+    /// # Error
+    ///
+    /// If a call diverges from the recorded calls, then an error is returned.
+    pub fn check(&self) -> WasmEdgeResult<()> {
+        match &self.lock().divergence {
+            Some((index, func_name)) => Err(WasmEdgeError::ReplayDiverged {
+                index: *index,
+                func_name: func_name.clone(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, TraceState> {
+        self.inner.lock().expect("[wasmedge-sys] try lock failed.")
+    }
+}
+
+#[derive(Debug, Default)]
+struct TraceState {
+    calls: Vec<HostCall>,
+    cursor: usize,
+    divergence: Option<(usize, String)>,
+}
+
+/// Struct of a call to a host function recorded in a [HostTrace].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HostCall {
+    /// The name of the host function.
+    pub func_name: String,
+    /// The arguments passed to the host function.
+    pub args: Vec<TraceValue>,
+    /// The returns of the host function, or the error code if the host function failed.
+    pub returns: Result<Vec<TraceValue>, u8>,
+}
+impl HostCall {
+    /// Checks if the arguments are the same as the recorded ones, comparing the floating-point values bitwise.
+    fn matches(&self, args: &[TraceValue]) -> bool {
+        self.args.len() == args.len()
+            && self
+                .args
+                .iter()
+                .zip(args)
+                .all(|(a, b)| a.to_bits() == b.to_bits())
+    }
+}
+
+/// Defines the numeric values recorded in a [HostCall].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TraceValue {
+    /// A 32-bit integer.
+    I32(i32),
+    /// A 64-bit integer.
+    I64(i64),
+    /// A 32-bit floating-point value.
+    F32(f32),
+    /// A 64-bit floating-point value.
+    F64(f64),
+    /// A 128-bit vector.
+    V128(i128),
+}
+impl TraceValue {
+    fn from_values(values: &[WasmValue]) -> Option<Vec<Self>> {
+        values
+            .iter()
+            .map(|value| match value.ty() {
+                ValType::I32 => Some(Self::I32(value.to_i32())),
+                ValType::I64 => Some(Self::I64(value.to_i64())),
+                ValType::F32 => Some(Self::F32(value.to_f32())),
+                ValType::F64 => Some(Self::F64(value.to_f64())),
+                ValType::V128 => Some(Self::V128(value.to_v128())),
+                ValType::FuncRef | ValType::ExternRef | ValType::None => None,
+            })
+            .collect()
+    }
+
+    fn to_value(self) -> WasmValue {
+        match self {
+            Self::I32(v) => WasmValue::from_i32(v),
+            Self::I64(v) => WasmValue::from_i64(v),
+            Self::F32(v) => WasmValue::from_f32(v),
+            Self::F64(v) => WasmValue::from_f64(v),
+            Self::V128(v) => WasmValue::from_v128(v),
+        }
+    }
+
+    fn to_bits(self) -> (u8, i128) {
+        match self {
+            Self::I32(v) => (0, v as i128),
+            Self::I64(v) => (1, v as i128),
+            Self::F32(v) => (2, v.to_bits() as i128),
+            Self::F64(v) => (3, v.to_bits() as i128),
+            Self::V128(v) => (4, v),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        Config, Executor, FuncType, GlobalType, MemType, Mutability, RefType, Statistics, Store,
-        TableType, ValType, Vm, WasmValue,
+        Caller, Config, Executor, FuncType, GlobalType, MemType, Mutability, RefType, Statistics,
+        Store, TableType, ValType, Vm, WasmValue,
     };
     use std::{
         sync::{Arc, Mutex},
@@ -508,6 +829,104 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_import_object_record_replay() {
+        use std::sync::atomic::{AtomicI64, Ordering};
+
+        // (module
+        //   (import "host" "next" (func $next (result i64)))
+        //   (func (export "run") (result i64)
+        //     (i64.add (call $next) (i64.mul (call $next) (i64.const 10)))))
+        let wasm = [
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x05, 0x01, 0x60, 0x00, 0x01,
+            0x7e, 0x02, 0x0d, 0x01, 0x04, 0x68, 0x6f, 0x73, 0x74, 0x04, 0x6e, 0x65, 0x78, 0x74,
+            0x00, 0x00, 0x03, 0x02, 0x01, 0x00, 0x07, 0x07, 0x01, 0x03, 0x72, 0x75, 0x6e, 0x00,
+            0x01, 0x0a, 0x0c, 0x01, 0x0a, 0x00, 0x10, 0x00, 0x10, 0x00, 0x42, 0x0a, 0x7e, 0x7c,
+            0x0b,
+        ];
+        let result = FuncType::create([], [ValType::I64]);
+        assert!(result.is_ok());
+        let func_ty = result.unwrap();
+
+        // record a run with a host function returning a different value on each call
+        let result = RecordingImportObject::create("host");
+        assert!(result.is_ok());
+        let mut recording = result.unwrap();
+        let counter = Arc::new(AtomicI64::new(0));
+        let result = recording.add_func(
+            "next",
+            &func_ty,
+            Box::new(move |_caller: &Caller, _inputs: Vec<WasmValue>| {
+                let n = counter.fetch_add(1, Ordering::SeqCst) + 1;
+                Ok(vec![WasmValue::from_i64(n)])
+            }),
+            0,
+        );
+        assert!(result.is_ok());
+        let trace = recording.trace();
+
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+        let result = vm.register_wasm_from_import(recording.into_import_object());
+        assert!(result.is_ok());
+        let result = vm.run_wasm_from_buffer(&wasm, "run", []);
+        assert!(result.is_ok());
+        let recorded_returns = result.unwrap();
+        assert_eq!(recorded_returns[0].to_i64(), 21);
+
+        // check the recorded calls
+        let calls = trace.calls();
+        assert_eq!(
+            calls,
+            vec![
+                HostCall {
+                    func_name: String::from("next"),
+                    args: vec![],
+                    returns: Ok(vec![TraceValue::I64(1)]),
+                },
+                HostCall {
+                    func_name: String::from("next"),
+                    args: vec![],
+                    returns: Ok(vec![TraceValue::I64(2)]),
+                },
+            ]
+        );
+
+        // replay the run
+        let result = ReplayImportObject::create("host", calls);
+        assert!(result.is_ok());
+        let mut replay = result.unwrap();
+        let result = replay.add_func("next", &func_ty, 0);
+        assert!(result.is_ok());
+        let trace = replay.trace();
+        assert_eq!(trace.remaining(), 2);
+
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+        let result = vm.register_wasm_from_import(replay.into_import_object());
+        assert!(result.is_ok());
+        let result = vm.run_wasm_from_buffer(&wasm, "run", []);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i64(), recorded_returns[0].to_i64());
+        assert_eq!(trace.remaining(), 0);
+        assert!(trace.check().is_ok());
+
+        // the calls beyond the recorded ones diverge
+        let result = vm.run_wasm_from_buffer(&wasm, "run", []);
+        assert!(result.is_err());
+        let result = trace.check();
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::ReplayDiverged {
+                index: 2,
+                func_name: String::from("next"),
+            }
+        );
+    }
+
     #[test]
     fn test_import_object_send() {
         let host_name = "extern";
@@ -633,7 +1052,7 @@ mod tests {
         handle.join().unwrap();
     }
 
-    fn real_add(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+    fn real_add(_caller: &Caller, inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
         if inputs.len() != 2 {
             return Err(1);
         }
@@ -2,16 +2,21 @@
 
 use crate::{
     error::{FuncError, WasmEdgeError},
-    ffi, HostFunc, ValType, WasmEdgeResult, HOST_FUNCS,
+    ffi,
+    instance::{
+        memory::{InnerMemory, Memory, MemoryRef},
+        module::with_host_state,
+    },
+    HostFunc, ValType, WasmEdgeResult, HOST_FUNCS,
 };
 use core::ffi::c_void;
 use rand::Rng;
-use std::convert::TryInto;
+use std::{convert::TryInto, sync::Arc};
 
 extern "C" fn wraper_fn(
     key_ptr: *mut c_void,
     _data: *mut c_void,
-    _mem_cxt: *mut ffi::WasmEdge_MemoryInstanceContext,
+    mem_cxt: *mut ffi::WasmEdge_MemoryInstanceContext,
     params: *const ffi::WasmEdge_Value,
     param_len: u32,
     returns: *mut ffi::WasmEdge_Value,
@@ -36,13 +41,26 @@ extern "C" fn wraper_fn(
         .expect("len of returns should not greater than usize");
     let raw_returns = unsafe { std::slice::from_raw_parts_mut(returns, return_len) };
 
-    let result = {
+    let caller = Caller {
+        mem: match mem_cxt.is_null() {
+            true => None,
+            false => Some(Memory {
+                inner: InnerMemory(mem_cxt),
+                registered: true,
+            }),
+        },
+    };
+
+    // release the lock before calling, so that the host functions can run concurrently
+    let real_fn = {
         let host_functions = HOST_FUNCS.lock().expect("[wasmedge-sys] try lock failed.");
-        let real_fn = host_functions
-            .get(&key)
-            .expect("host function should be there");
-        real_fn(input)
+        Arc::clone(
+            host_functions
+                .get(&key)
+                .expect("host function should be there"),
+        )
     };
+    let result = real_fn(&caller, input);
 
     match result {
         Ok(v) => {
@@ -56,6 +74,58 @@ extern "C" fn wraper_fn(
     }
 }
 
+/// Struct of WasmEdge Caller.
+///
+/// A [Caller] is passed to a [host function](crate::Function) on each call, and describes the WASM module instance that
+/// invokes the host function. Through the [Caller], the host function can access the memory of the calling module, for
+/// example, to dereference a pointer argument, and the host states attached to the calling module.
+///
+/// # Notice
+///
+/// WasmEdge only passes the memory instance at index 0 of the calling module to the host function, so neither looking up
+/// a memory by its export name nor accessing other exports of the calling module is supported.
+#[derive(Debug)]
+pub struct Caller {
+    // the memory owned by the calling module, which is only valid during the call to the host function
+    mem: Option<Memory>,
+}
+impl Caller {
+    /// Returns a read-only view of the [Memory] of the calling module, which borrows the [Caller] and therefore
+    /// cannot outlive the call to the host function.
+    ///
+    /// If the calling module defines or imports no memory, then `None` is returned.
+    ///
+    /// # Example
+    ///
+    /// The view cannot escape the host function:
+    ///
+    /// ```compile_fail
+    /// use wasmedge_sys::{Caller, MemoryRef};
+    ///
+    /// fn leak(caller: &Caller) -> MemoryRef<'static> {
+    ///     caller.memory().unwrap()
+    /// }
+    /// ```
+    pub fn memory(&self) -> Option<MemoryRef<'_>> {
+        self.mem.as_ref().map(Memory::as_ref)
+    }
+
+    /// Calls `f` with a reference to the host state of type `T` attached to the calling module instance, and returns
+    /// the result, or returns `None` if no state of the type is attached. See
+    /// [Instance::set_host_state](crate::Instance::set_host_state).
+    pub fn host_state<T: Send + 'static, R>(&self, f: impl FnOnce(&T) -> R) -> Option<R> {
+        self.host_state_mut(|state: &mut T| f(&*state))
+    }
+
+    /// Calls `f` with a mutable reference to the host state of type `T` attached to the calling module instance, and
+    /// returns the result, or returns `None` if no state of the type is attached. See
+    /// [Instance::set_host_state](crate::Instance::set_host_state).
+    pub fn host_state_mut<T: Send + 'static, R>(&self, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+        let mem = self.mem.as_ref()?;
+        with_host_state(mem.inner.0 as usize, f)
+    }
+}
+
 /// Struct of WasmEdge Function.
 ///
 /// A WasmEdge [Function] defines a host function described by its [FuncType]. A host function is a function defined outside WASM module and passed to it.
@@ -76,7 +146,7 @@ impl Function {
     ///
     /// - `ty` specifies the types of the arguments and returns of the target function.
     ///
-    /// - `real_fn` specifies the pointer to the target function.
+    /// - `real_fn` specifies the pointer to the target function. On each call, it receives the [Caller] of the function and the arguments.
     ///
     /// - `cost` specifies the function cost in the [Statistics](crate::Statistics).
     ///
@@ -90,9 +160,9 @@ impl Function {
     /// the `create_binding` method.
     ///
     /// ```rust
-    /// use wasmedge_sys::{FuncType, Function, ValType, WasmValue, WasmEdgeResult};
+    /// use wasmedge_sys::{Caller, FuncType, Function, ValType, WasmValue, WasmEdgeResult};
     ///
-    /// fn real_add(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+    /// fn real_add(_caller: &Caller, inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
     ///     if inputs.len() != 2 {
     ///         return Err(1);
     ///     }
@@ -135,7 +205,7 @@ impl Function {
         while host_functions.contains_key(&key) {
             key = rng.gen();
         }
-        host_functions.insert(key, real_fn);
+        host_functions.insert(key, Arc::new(real_fn));
 
         let ctx = unsafe {
             ffi::WasmEdge_FunctionInstanceCreateBinding(
@@ -312,7 +382,7 @@ unsafe impl Sync for InnerFuncType {}
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Executor, ImportObject, Store, ValType, WasmValue};
+    use crate::{Executor, ImportObject, Store, ValType, Vm, WasmValue};
     use std::{
         sync::{Arc, Mutex},
         thread,
@@ -508,7 +578,77 @@ mod tests {
         handle.join().unwrap();
     }
 
-    fn real_add(input: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+    #[test]
+    fn test_func_caller_memory() {
+        // create an ImportObject module
+        let result = ImportObject::create("extern");
+        assert!(result.is_ok());
+        let mut import_obj = result.unwrap();
+
+        // add a host function which reads a string from the memory of the caller
+        let result = FuncType::create(vec![ValType::I32; 2], vec![ValType::I32]);
+        assert!(result.is_ok());
+        let func_ty = result.unwrap();
+        let result = Function::create(&func_ty, Box::new(real_print_str), 0);
+        assert!(result.is_ok());
+        let host_func = result.unwrap();
+        import_obj.add_func("print_str", host_func);
+
+        // create a Vm context
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        // register the import_obj module
+        let result = vm.register_wasm_from_import(import_obj);
+        assert!(result.is_ok());
+
+        // (module
+        //   (import "extern" "print_str" (func $print_str (param i32 i32) (result i32)))
+        //   (memory (export "memory") 1)
+        //   (data (i32.const 0) "hello")
+        //   (func (export "run") (result i32)
+        //     i32.const 0
+        //     i32.const 5
+        //     call $print_str))
+        let wasm: &[u8] = &[
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x0b, 0x02, 0x60, 0x02, 0x7f,
+            0x7f, 0x01, 0x7f, 0x60, 0x00, 0x01, 0x7f, 0x02, 0x14, 0x01, 0x06, 0x65, 0x78, 0x74,
+            0x65, 0x72, 0x6e, 0x09, 0x70, 0x72, 0x69, 0x6e, 0x74, 0x5f, 0x73, 0x74, 0x72, 0x00,
+            0x00, 0x03, 0x02, 0x01, 0x01, 0x05, 0x03, 0x01, 0x00, 0x01, 0x07, 0x10, 0x02, 0x06,
+            0x6d, 0x65, 0x6d, 0x6f, 0x72, 0x79, 0x02, 0x00, 0x03, 0x72, 0x75, 0x6e, 0x00, 0x01,
+            0x0a, 0x0a, 0x01, 0x08, 0x00, 0x41, 0x00, 0x41, 0x05, 0x10, 0x00, 0x0b, 0x0b, 0x0b,
+            0x01, 0x00, 0x41, 0x00, 0x0b, 0x05, 0x68, 0x65, 0x6c, 0x6c, 0x6f,
+        ];
+
+        // run the wasm function which passes a string pointer to the host function
+        let result = vm.run_wasm_from_buffer(wasm, "run", []);
+        assert!(result.is_ok());
+        let returns = result.unwrap();
+        assert_eq!(returns.len(), 1);
+        assert_eq!(returns[0].to_i32(), 5);
+    }
+
+    fn real_print_str(caller: &Caller, input: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+        if input.len() != 2 {
+            return Err(1);
+        }
+
+        let offset = input[0].to_i32() as u32;
+        let len = input[1].to_i32() as u32;
+
+        // read the string from the memory of the caller
+        let mem = caller.memory().ok_or(2)?;
+        let data = mem.get_data(offset, len).map_err(|_| 3)?;
+        let s = String::from_utf8(data).map_err(|_| 4)?;
+        if s != "hello" {
+            return Err(5);
+        }
+
+        Ok(vec![WasmValue::from_i32(s.len() as i32)])
+    }
+
+    fn real_add(_caller: &Caller, input: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
         println!("Rust: Entering Rust function real_add");
 
         if input.len() != 2 {
@@ -4,16 +4,67 @@
 //! `MemType` specifies the limits on the size of a memory by a range. The start of
 //! the limit range specifies min size (initial size) of that memory, while the end
 //! restricts the size to which the memory can grow later.
+//!
+//! Notice that shared memories defined in the threads proposal are not supported yet, since `MemType` cannot
+//! describe a shared memory through the WasmEdge C API. As a result, the host cannot take part in the
+//! `memory.atomic.wait`/`memory.atomic.notify` synchronization of the guest threads.
 
 use crate::{
     error::{check, MemError, WasmEdgeError},
     ffi, WasmEdgeResult,
 };
-use std::ops::RangeInclusive;
+use std::{marker::PhantomData, ops::RangeInclusive};
+
+macro_rules! impl_read_endian {
+    ($($ty:ident),+) => {
+        $(
+            paste::paste! {
+                #[doc = "Reads a little-endian `" $ty "` value from the [Memory]."]
+                ///
+                /// # Arguments
+                ///
+                /// - `offset` specifies the data start offset in the [Memory].
+                ///
+                /// # Errors
+                ///
+                /// If the value is out of the data size in the [Memory], then an error is returned.
+                pub fn [<read_ $ty _le>](&self, offset: u32) -> WasmEdgeResult<$ty> {
+                    let data = self.get_data(offset, std::mem::size_of::<$ty>() as u32)?;
+                    let mut bytes = [0u8; std::mem::size_of::<$ty>()];
+                    bytes.copy_from_slice(&data);
+                    Ok($ty::from_le_bytes(bytes))
+                }
+
+                #[doc = "Reads a big-endian `" $ty "` value from the [Memory]."]
+                ///
+                /// WASM memory is always little-endian, so this method is only useful for the data stored in the
+                /// big-endian byte order, such as the network-format data.
+                ///
+                /// # Arguments
+                ///
+                /// - `offset` specifies the data start offset in the [Memory].
+                ///
+                /// # Errors
+                ///
+                /// If the value is out of the data size in the [Memory], then an error is returned.
+                pub fn [<read_ $ty _be>](&self, offset: u32) -> WasmEdgeResult<$ty> {
+                    let data = self.get_data(offset, std::mem::size_of::<$ty>() as u32)?;
+                    let mut bytes = [0u8; std::mem::size_of::<$ty>()];
+                    bytes.copy_from_slice(&data);
+                    Ok($ty::from_be_bytes(bytes))
+                }
+            }
+        )+
+    };
+}
 
 /// Struct of WasmEdge Memory.
 ///
 /// A WasmEdge [Memory] defines a linear memory as described by [MemType].
+///
+/// The data of a [Memory] is always allocated and owned by WasmEdge, since the WasmEdge C API provides no way to
+/// create a [Memory] backed by a host buffer. To access the data without copying, use [Memory::data_pointer] and
+/// [Memory::data_pointer_mut] instead.
 #[derive(Debug)]
 pub struct Memory {
     pub(crate) inner: InnerMemory,
@@ -81,9 +132,11 @@ impl Memory {
     ///
     /// # Errors
     ///
-    /// If the `offset + len` is larger than the data size in the [Memory], then an error is returned.
+    /// If the `offset + len` is larger than the data size in the [Memory], then a [MemError::OutOfBounds] error is
+    /// returned.
     ///
     pub fn get_data(&self, offset: u32, len: u32) -> WasmEdgeResult<Vec<u8>> {
+        self.check_bounds(offset, len)?;
         let mut data = Vec::with_capacity(len as usize);
         unsafe {
             check(ffi::WasmEdge_MemoryInstanceGetData(
@@ -95,7 +148,237 @@ impl Memory {
             data.set_len(len as usize);
         }
 
-        Ok(data.into_iter().collect())
+        Ok(data)
+    }
+
+    /// Feeds the data in the range of `len` bytes starting from `offset` in the [Memory] to the given sink, in the
+    /// pieces of at most `chunk_size` bytes.
+    ///
+    /// The data is read in place, so a large region can be written to a file or a socket without copying the whole
+    /// region out of the [Memory] first.
+    ///
+    /// # Arguments
+    ///
+    /// - `offset` specifies the data start offset in the [Memory].
+    ///
+    /// - `len` specifies the requested data length.
+    ///
+    /// - `chunk_size` specifies the maximum length of each piece fed to the sink.
+    ///
+    /// - `sink` specifies the closure consuming the pieces in order.
+    ///
+    /// # Errors
+    ///
+    /// If the `offset + len` is larger than the data size in the [Memory], or `chunk_size` is zero, then an error is
+    /// returned. If the sink returns an error, then the streaming is aborted and a [MemError::Io] error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use wasmedge_sys::{MemType, Memory};
+    /// use std::io::Write;
+    ///
+    /// // create a Memory: the min size 1 and the max size 2
+    /// let ty = MemType::create(1..=2).expect("fail to create a memory type");
+    /// let mem = Memory::create(&ty).expect("fail to create a Memory");
+    ///
+    /// // write the first page to stdout in 4 KiB pieces
+    /// let mut stdout = std::io::stdout();
+    /// mem.stream_region(0, 65536, 4096, |chunk| stdout.write_all(chunk))
+    ///     .expect("fail to stream the data");
+    /// ```
+    pub fn stream_region(
+        &self,
+        offset: u32,
+        len: u32,
+        chunk_size: u32,
+        mut sink: impl FnMut(&[u8]) -> std::io::Result<()>,
+    ) -> WasmEdgeResult<()> {
+        if chunk_size == 0 {
+            return Err(WasmEdgeError::Mem(MemError::ZeroChunkSize));
+        }
+        self.check_bounds(offset, len)?;
+
+        let mut streamed = 0;
+        while streamed < len {
+            let size = chunk_size.min(len - streamed);
+            let ptr = self.raw_pointer(offset + streamed, size)?;
+            let chunk = unsafe { std::slice::from_raw_parts(ptr, size as usize) };
+            sink(chunk).map_err(|e| WasmEdgeError::Mem(MemError::Io(e.to_string())))?;
+            streamed += size;
+        }
+        Ok(())
+    }
+
+    /// Returns the offset of the first nonzero byte in the range of `len` bytes starting from `offset` in the [Memory],
+    /// or `None` if all the bytes in the range are zero.
+    ///
+    /// The range is scanned in place without copying it out of the [Memory].
+    ///
+    /// # Arguments
+    ///
+    /// - `offset` specifies the data start offset in the [Memory].
+    ///
+    /// - `len` specifies the length of the range to scan.
+    ///
+    /// # Errors
+    ///
+    /// If the `offset + len` is larger than the data size in the [Memory], then an error is returned.
+    pub fn find_nonzero(&self, offset: u32, len: u32) -> WasmEdgeResult<Option<u32>> {
+        self.check_bounds(offset, len)?;
+        if len == 0 {
+            return Ok(None);
+        }
+
+        let ptr = self.raw_pointer(offset, len)?;
+        let data = unsafe { std::slice::from_raw_parts(ptr, len as usize) };
+        Ok(data
+            .iter()
+            .position(|&byte| byte != 0)
+            .map(|pos| offset + pos as u32))
+    }
+
+    /// Returns the indices of the pages of which the data differs from the given baseline.
+    ///
+    /// WasmEdge does not track the dirty pages, so the data of each page is compared with the baseline. A page not
+    /// fully covered by the baseline, for example, a page added by growing the [Memory] after the baseline was taken,
+    /// is reported as changed.
+    ///
+    /// # Argument
+    ///
+    /// - `baseline` specifies the data of the [Memory] taken earlier, for example, by [Memory::get_data].
+    ///
+    /// # Errors
+    ///
+    /// If fail to get the data of a page, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use wasmedge_sys::{MemType, Memory};
+    ///
+    /// // create a Memory: the min size 2 and the max size 2
+    /// let ty = MemType::create(2..=2).expect("fail to create a memory type");
+    /// let mut mem = Memory::create(&ty).expect("fail to create a Memory");
+    ///
+    /// // take a baseline and change the second page
+    /// let baseline = mem.get_data(0, 2 * 65536).expect("fail to get data");
+    /// mem.set_data(vec![1], 65536).expect("fail to set data");
+    /// assert_eq!(mem.dirty_pages_since(&baseline).expect("fail to compare"), vec![1]);
+    /// ```
+    pub fn dirty_pages_since(&self, baseline: &[u8]) -> WasmEdgeResult<Vec<u32>> {
+        let mut dirty_pages = Vec::new();
+        for page in 0..self.size() {
+            let ptr = self.raw_pointer(page * PAGE_SIZE, PAGE_SIZE)?;
+            let data = unsafe { std::slice::from_raw_parts(ptr, PAGE_SIZE as usize) };
+            let start = page as usize * PAGE_SIZE as usize;
+            let end = start + PAGE_SIZE as usize;
+            if baseline.get(start..end) != Some(data) {
+                dirty_pages.push(page);
+            }
+        }
+        Ok(dirty_pages)
+    }
+
+    /// Reads an unsigned little-endian integer of `nbytes` bytes from the [Memory], and zero-extends it to `u64`.
+    ///
+    /// # Arguments
+    ///
+    /// - `offset` specifies the data start offset in the [Memory].
+    ///
+    /// - `nbytes` specifies the width of the integer in bytes. It should be in the range `[1, 8]`.
+    ///
+    /// # Errors
+    ///
+    /// If `nbytes` is out of the range `[1, 8]`, or the `offset + nbytes` is larger than the data size in the
+    /// [Memory], then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use wasmedge_sys::{MemType, Memory};
+    ///
+    /// // create a Memory: the min size 1 and the max size 2
+    /// let ty = MemType::create(1..=2).expect("fail to create a memory type");
+    /// let mut mem = Memory::create(&ty).expect("fail to create a Memory");
+    ///
+    /// // read a 3-byte integer
+    /// mem.set_data(vec![0x01, 0x02, 0x03], 0).expect("fail to set data");
+    /// assert_eq!(mem.read_uint(0, 3).expect("fail to read data"), 0x030201);
+    /// ```
+    pub fn read_uint(&self, offset: u32, nbytes: u8) -> WasmEdgeResult<u64> {
+        if !(1..=8).contains(&nbytes) {
+            return Err(WasmEdgeError::Mem(MemError::InvalidByteWidth(nbytes)));
+        }
+
+        let data = self.get_data(offset, nbytes as u32)?;
+        let mut bytes = [0u8; 8];
+        bytes[..data.len()].copy_from_slice(&data);
+        Ok(u64::from_le_bytes(bytes))
+    }
+
+    /// Reads a signed little-endian integer of `nbytes` bytes from the [Memory], and sign-extends it to `i64`.
+    ///
+    /// # Arguments
+    ///
+    /// - `offset` specifies the data start offset in the [Memory].
+    ///
+    /// - `nbytes` specifies the width of the integer in bytes. It should be in the range `[1, 8]`.
+    ///
+    /// # Errors
+    ///
+    /// If `nbytes` is out of the range `[1, 8]`, or the `offset + nbytes` is larger than the data size in the
+    /// [Memory], then an error is returned.
+    pub fn read_int(&self, offset: u32, nbytes: u8) -> WasmEdgeResult<i64> {
+        let value = self.read_uint(offset, nbytes)?;
+        let shift = 64 - 8 * nbytes as u32;
+        Ok(((value << shift) as i64) >> shift)
+    }
+
+    impl_read_endian!(u32, i32, u64, i64);
+
+    /// Reads a string of `units` little-endian UTF-16 code units from the [Memory].
+    ///
+    /// # Arguments
+    ///
+    /// - `offset` specifies the data start offset in the [Memory].
+    ///
+    /// - `units` specifies the number of the 16-bit code units to read.
+    ///
+    /// # Errors
+    ///
+    /// If the `offset + units * 2` is larger than the data size in the [Memory], or the code units contain an unpaired
+    /// surrogate, then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use wasmedge_sys::{MemType, Memory};
+    ///
+    /// // create a Memory: the min size 1 and the max size 2
+    /// let ty = MemType::create(1..=2).expect("fail to create a memory type");
+    /// let mut mem = Memory::create(&ty).expect("fail to create a Memory");
+    ///
+    /// // read "hi" encoded in UTF-16LE
+    /// mem.set_data(vec![0x68, 0x00, 0x69, 0x00], 0).expect("fail to set data");
+    /// assert_eq!(mem.read_utf16(0, 2).expect("fail to read data"), "hi");
+    /// ```
+    pub fn read_utf16(&self, offset: u32, units: u32) -> WasmEdgeResult<String> {
+        let len = units
+            .checked_mul(2)
+            .ok_or(WasmEdgeError::Mem(MemError::LengthOverflow {
+                offset,
+                len: units as u64 * 2,
+            }))?;
+        let data = self.get_data(offset, len)?;
+        let units = data
+            .chunks_exact(2)
+            .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]));
+        char::decode_utf16(units)
+            .map(|c| {
+                c.map_err(|e| WasmEdgeError::Mem(MemError::InvalidUtf16(e.unpaired_surrogate())))
+            })
+            .collect()
     }
 
     /// Copies the data from the given input buffer into the [Memory].
@@ -109,10 +392,10 @@ impl Memory {
     /// # Errors
     ///
     /// If the sum of the `offset` and the data length is larger than the size of the [Memory],
-    /// then an error is returned.
+    /// then a [MemError::OutOfBounds] error is returned.
     ///
     /// ```
-    /// use wasmedge_sys::{error::{CoreError, CoreExecutionError, WasmEdgeError}, Memory, MemType};
+    /// use wasmedge_sys::{error::{MemError, WasmEdgeError}, Memory, MemType};
     ///
     /// // create a Memory: the min size 1 and the max size 2
     /// let ty = MemType::create(1..=2).expect("fail to create a memory type");
@@ -121,7 +404,7 @@ impl Memory {
     /// // set data and the data length is larger than the data size in the memory
     /// let result = mem.set_data(vec![1; 10], u32::pow(2, 16) - 9);
     /// assert!(result.is_err());
-    /// assert_eq!(result.unwrap_err(), WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::MemoryOutOfBounds)));
+    /// assert_eq!(result.unwrap_err(), WasmEdgeError::Mem(MemError::OutOfBounds { offset: 65527, len: 10, byte_len: 65536 }));
     /// ```
     ///
     /// # Example
@@ -150,6 +433,7 @@ impl Memory {
         offset: u32,
     ) -> WasmEdgeResult<()> {
         let data = data.into_iter().collect::<Vec<u8>>();
+        self.check_bounds(offset, data.len() as u32)?;
         unsafe {
             check(ffi::WasmEdge_MemoryInstanceSetData(
                 self.inner.0,
@@ -175,16 +459,27 @@ impl Memory {
     /// If fail to get the data pointer, then an error is returned.
     ///
     pub fn data_pointer(&self, offset: u32, len: u32) -> WasmEdgeResult<&u8> {
+        let ptr = self.raw_pointer(offset, len)?;
+        let result = unsafe { ptr.as_ref() };
+        match result {
+            Some(ptr) => Ok(ptr),
+            None => Err(WasmEdgeError::Mem(MemError::Ptr2Ref)),
+        }
+    }
+
+    /// Returns the raw const pointer to the range of `len` bytes starting from `offset` in the [Memory].
+    ///
+    /// Unlike the reference returned by [Memory::data_pointer], the raw pointer is valid for reads of the whole range,
+    /// so it can be used to build a slice over the range.
+    pub(crate) fn raw_pointer(&self, offset: u32, len: u32) -> WasmEdgeResult<*const u8> {
         let ptr = unsafe { ffi::WasmEdge_MemoryInstanceGetPointerConst(self.inner.0, offset, len) };
         match ptr.is_null() {
-            true => Err(WasmEdgeError::Mem(MemError::ConstPtr)),
-            false => {
-                let result = unsafe { ptr.as_ref() };
-                match result {
-                    Some(ptr) => Ok(ptr),
-                    None => Err(WasmEdgeError::Mem(MemError::Ptr2Ref)),
-                }
-            }
+            true => Err(WasmEdgeError::Mem(MemError::ConstPtr {
+                offset,
+                len,
+                byte_len: self.byte_len(),
+            })),
+            false => Ok(ptr),
         }
     }
 
@@ -202,19 +497,98 @@ impl Memory {
     /// If fail to get the data pointer, then an error is returned.
     ///
     pub fn data_pointer_mut(&mut self, offset: u32, len: u32) -> WasmEdgeResult<&mut u8> {
+        let ptr = self.raw_pointer_mut(offset, len)?;
+        let result = unsafe { ptr.as_mut() };
+        match result {
+            Some(ptr) => Ok(ptr),
+            None => Err(WasmEdgeError::Mem(MemError::Ptr2Ref)),
+        }
+    }
+
+    /// Returns the raw mutable pointer to the range of `len` bytes starting from `offset` in the [Memory].
+    ///
+    /// Unlike the reference returned by [Memory::data_pointer_mut], the raw pointer is valid for reads and writes of
+    /// the whole range, so it can be used to build a mutable slice over the range.
+    pub(crate) fn raw_pointer_mut(&mut self, offset: u32, len: u32) -> WasmEdgeResult<*mut u8> {
         let ptr = unsafe { ffi::WasmEdge_MemoryInstanceGetPointer(self.inner.0, offset, len) };
         match ptr.is_null() {
-            true => Err(WasmEdgeError::Mem(MemError::MutPtr)),
-            false => {
-                let result = unsafe { ptr.as_mut() };
-                match result {
-                    Some(ptr) => Ok(ptr),
-                    None => Err(WasmEdgeError::Mem(MemError::Ptr2Ref)),
-                }
-            }
+            true => Err(WasmEdgeError::Mem(MemError::MutPtr {
+                offset,
+                len,
+                byte_len: self.byte_len(),
+            })),
+            false => Ok(ptr),
+        }
+    }
+
+    /// Registers a region of `count` elements of type `T` starting at `offset` in the [Memory].
+    ///
+    /// The returned [RegionHandle] does not borrow the [Memory]. Every time a view is produced from it, the region is
+    /// validated against the [Memory] again, so the handle keeps working after the [Memory] grows.
+    ///
+    /// # Arguments
+    ///
+    /// - `offset` specifies the start offset of the region in the [Memory].
+    ///
+    /// - `count` specifies the number of the elements in the region.
+    ///
+    /// # Errors
+    ///
+    /// If the region is out of the bounds of the [Memory], or the start address is not aligned to `T`, then an error
+    /// is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use wasmedge_sys::{MemType, Memory};
+    ///
+    /// // create a Memory: the min size 1 and the max size 2
+    /// let ty = MemType::create(1..=2).expect("fail to create a memory type");
+    /// let mut mem = Memory::create(&ty).expect("fail to create a Memory");
+    ///
+    /// // register a region of four i32 values
+    /// let region = mem.register_region::<i32>(16, 4).expect("fail to register a region");
+    /// region.as_mut_slice(&mut mem).expect("fail to get the region")[0] = 42;
+    /// assert_eq!(region.as_slice(&mem).expect("fail to get the region")[0], 42);
+    /// ```
+    pub fn register_region<T: Pod>(
+        &mut self,
+        offset: u32,
+        count: u32,
+    ) -> WasmEdgeResult<RegionHandle<T>> {
+        let region = RegionHandle {
+            offset,
+            count,
+            _marker: PhantomData,
+        };
+        region.as_slice(self)?;
+        Ok(region)
+    }
+
+    /// Returns the size in bytes of the [Memory].
+    fn byte_len(&self) -> u64 {
+        self.size() as u64 * PAGE_SIZE as u64
+    }
+
+    /// Checks if the range of `len` bytes starting from `offset` is in the bounds of the [Memory].
+    fn check_bounds(&self, offset: u32, len: u32) -> WasmEdgeResult<()> {
+        let byte_len = self.byte_len();
+        match offset as u64 + len as u64 > byte_len {
+            true => Err(WasmEdgeError::Mem(MemError::OutOfBounds {
+                offset,
+                len,
+                byte_len,
+            })),
+            false => Ok(()),
         }
     }
 
+    /// Returns a read-only view of the [Memory].
+    #[allow(clippy::should_implement_trait)]
+    pub fn as_ref(&self) -> MemoryRef<'_> {
+        MemoryRef { mem: self }
+    }
+
     /// Returns the size, in WebAssembly pages (64 KiB of each page), of this wasm memory.
     pub fn size(&self) -> u32 {
         unsafe { ffi::WasmEdge_MemoryInstanceGetPageSize(self.inner.0) as u32 }
@@ -250,89 +624,375 @@ impl Memory {
     pub fn grow(&mut self, count: u32) -> WasmEdgeResult<()> {
         unsafe { check(ffi::WasmEdge_MemoryInstanceGrowPage(self.inner.0, count)) }
     }
-}
-impl Drop for Memory {
-    fn drop(&mut self) {
-        if !self.registered && !self.inner.0.is_null() {
-            unsafe { ffi::WasmEdge_MemoryInstanceDelete(self.inner.0) };
+
+    /// Returns whether growing the [Memory] by `count` pages may relocate its data, which invalidates the pointers
+    /// returned by [Memory::data_pointer] and [Memory::data_pointer_mut].
+    ///
+    /// On x86_64 and aarch64 hosts, and on 64-bit Windows, WasmEdge reserves the whole 4 GiB address space of a
+    /// [Memory] when creating it, and grows it in place, so `false` is returned if the [Memory] stays within its
+    /// limit after growing. On the other hosts, WasmEdge reallocates the data when growing, so any growth is
+    /// conservatively assumed to relocate the data, and `false` is only returned if `count` is zero.
+    ///
+    /// # Arguments
+    ///
+    /// - `count` specifies the page counts to be extended to the [Memory].
+    pub fn would_relocate_on_grow(&self, count: u32) -> bool {
+        if count == 0 {
+            return false;
+        }
+        if !cfg!(any(
+            target_arch = "x86_64",
+            target_arch = "aarch64",
+            all(windows, target_pointer_width = "64")
+        )) {
+            return true;
         }
-    }
-}
 
-#[derive(Debug)]
-pub(crate) struct InnerMemory(pub(crate) *mut ffi::WasmEdge_MemoryInstanceContext);
-unsafe impl Send for InnerMemory {}
-unsafe impl Sync for InnerMemory {}
+        // the capacity reserved for the memory is the max size, or 4 GiB if the memory has no max size
+        let max = match self.ty() {
+            Ok(ty) => {
+                let limit = unsafe { ffi::WasmEdge_MemoryTypeGetLimit(ty.inner.0) };
+                match limit.HasMax {
+                    true => limit.Max as u64,
+                    false => (u32::MAX as u64 + 1) / PAGE_SIZE as u64,
+                }
+            }
+            Err(_) => return true,
+        };
+        self.size() as u64 + count as u64 > max
+    }
 
-/// Struct of WasmEdge MemType.
-///
-/// A [MemType] classifies a [Memory] and its size range.
-#[derive(Debug)]
-pub struct MemType {
-    pub(crate) inner: InnerMemType,
-    pub(crate) registered: bool,
-}
-impl MemType {
-    /// Create a new [MemType] to be associated with the given limit range for the capacity.
+    /// Grows the [Memory] so that its size in bytes is at least `bytes`. If the [Memory] is large enough, then it is
+    /// left unchanged.
     ///
-    /// # Arguments
+    /// # Argument
     ///
-    /// - `limit` specifies the linear memory size. The start value of the limit range specifies the min size (also, initial size) of the memory, while the end value specifies the max size allowed to grow. The maximum size is `u32::MAX`.
+    /// - `bytes` specifies the required size in bytes. The number of the pages to grow is rounded up.
     ///
     /// # Errors
     ///
-    /// If fail to create a [MemType], then an error is returned.
+    /// If the required size exceeds 4 GiB, the size of the 32-bit address space, then a
+    /// [CapacityOverflow](crate::error::MemError::CapacityOverflow) error is returned. If the required size exceeds
+    /// the max size of the [Memory], then an error is returned.
     ///
     /// # Example
     ///
-    /// ```ignore
-    /// let ty = MemType::create(0..=u32::MAX);
     /// ```
+    /// use wasmedge_sys::{MemType, Memory};
     ///
-    pub fn create(limit: RangeInclusive<u32>) -> WasmEdgeResult<Self> {
-        let ctx = unsafe { ffi::WasmEdge_MemoryTypeCreate(ffi::WasmEdge_Limit::from(limit)) };
-        match ctx.is_null() {
-            true => Err(WasmEdgeError::MemTypeCreate),
-            false => Ok(Self {
-                inner: InnerMemType(ctx),
-                registered: false,
-            }),
+    /// // create a Memory: the min size 1 and the max size 3
+    /// let ty = MemType::create(1..=3).expect("fail to create a memory type");
+    /// let mut mem = Memory::create(&ty).expect("fail to create a Memory");
+    ///
+    /// // one byte more than a page requires two pages
+    /// mem.ensure_capacity(65537).expect("fail to grow the memory");
+    /// assert_eq!(mem.size(), 2);
+    /// ```
+    pub fn ensure_capacity(&mut self, bytes: u64) -> WasmEdgeResult<()> {
+        if bytes > u32::MAX as u64 + 1 {
+            return Err(WasmEdgeError::Mem(MemError::CapacityOverflow(bytes)));
+        }
+        let page_size = PAGE_SIZE as u64;
+        // at most 65536 pages, which fits in u32
+        let pages = (bytes / page_size + u64::from(bytes % page_size != 0)) as u32;
+        if pages > self.size() {
+            self.grow(pages - self.size())?;
         }
+        Ok(())
     }
 
-    /// Returns the limit range of a [MemType].
+    /// Returns the whole data in the [Memory] compressed with zstd.
     ///
-    /// # Example
+    /// The snapshot can be restored by calling [Memory::restore_compressed].
     ///
-    /// ```
-    /// use wasmedge_sys::MemType;
+    /// # Error
     ///
-    /// let ty = MemType::create(0..=u32::MAX).expect("fail to create a MemType");
-    /// assert_eq!(ty.limit(), 0..=u32::MAX);
-    /// ```
+    /// If fail to read or compress the data, then an error is returned.
+    #[cfg(feature = "compression")]
+    pub fn snapshot_compressed(&self) -> WasmEdgeResult<Vec<u8>> {
+        use std::io::Write;
+
+        let mut encoder = zstd::stream::Encoder::new(Vec::new(), 0)
+            .map_err(|e| WasmEdgeError::Mem(MemError::Compress(e.to_string())))?;
+        for page in 0..self.size() {
+            let data = self.get_data(page * PAGE_SIZE, PAGE_SIZE)?;
+            encoder
+                .write_all(&data)
+                .map_err(|e| WasmEdgeError::Mem(MemError::Compress(e.to_string())))?;
+        }
+        encoder
+            .finish()
+            .map_err(|e| WasmEdgeError::Mem(MemError::Compress(e.to_string())))
+    }
+
+    /// Restores the data in the [Memory] from a snapshot returned by [Memory::snapshot_compressed].
     ///
-    pub fn limit(&self) -> RangeInclusive<u32> {
-        let limit = unsafe { ffi::WasmEdge_MemoryTypeGetLimit(self.inner.0) };
-        RangeInclusive::from(limit)
+    /// If the snapshot is larger than the [Memory], then the [Memory] grows to fit it. The data beyond the size of the
+    /// snapshot is left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// - `snapshot` specifies the compressed data to restore.
+    ///
+    /// # Error
+    ///
+    /// If fail to decompress the snapshot, or fail to grow the [Memory], then an error is returned.
+    #[cfg(feature = "compression")]
+    pub fn restore_compressed(&mut self, snapshot: &[u8]) -> WasmEdgeResult<()> {
+        let data = zstd::stream::decode_all(snapshot)
+            .map_err(|e| WasmEdgeError::Mem(MemError::Decompress(e.to_string())))?;
+
+        self.ensure_capacity(data.len() as u64)?;
+
+        for (page, chunk) in data.chunks(PAGE_SIZE as usize).enumerate() {
+            self.set_data(chunk.iter().copied(), page as u32 * PAGE_SIZE)?;
+        }
+        Ok(())
     }
 }
-impl Drop for MemType {
+impl Drop for Memory {
     fn drop(&mut self) {
         if !self.registered && !self.inner.0.is_null() {
-            unsafe { ffi::WasmEdge_MemoryTypeDelete(self.inner.0) }
+            unsafe { ffi::WasmEdge_MemoryInstanceDelete(self.inner.0) };
         }
     }
 }
 
-#[derive(Debug)]
-pub(crate) struct InnerMemType(pub(crate) *mut ffi::WasmEdge_MemoryTypeContext);
-unsafe impl Send for InnerMemType {}
-unsafe impl Sync for InnerMemType {}
-
-#[cfg(test)]
+/// Struct of a read-only view of a [Memory].
+///
+/// A [MemoryRef] is returned by [Memory::as_ref]. It only exposes the methods reading the [Memory], so the [Memory]
+/// can be handed to the untrusted consumers without the risk of being modified by them.
+///
+/// # Example
+///
+/// ```compile_fail
+/// use wasmedge_sys::{MemType, Memory};
+///
+/// let ty = MemType::create(1..=2).expect("fail to create a memory type");
+/// let mem = Memory::create(&ty).expect("fail to create a Memory");
+///
+/// // a MemoryRef has no set_data method
+/// mem.as_ref().set_data(vec![1, 2, 3], 0);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct MemoryRef<'a> {
+    mem: &'a Memory,
+}
+impl<'a> MemoryRef<'a> {
+    /// Returns the type of the [Memory].
+    ///
+    /// # Errors
+    ///
+    /// If fail to get the type of the [Memory], then an error is returned.
+    pub fn ty(&self) -> WasmEdgeResult<MemType> {
+        self.mem.ty()
+    }
+
+    /// Returns the size, in WebAssembly pages (64 KiB of each page), of the [Memory].
+    pub fn size(&self) -> u32 {
+        self.mem.size()
+    }
+
+    /// Copies the data from the [Memory] to the output buffer. See [Memory::get_data].
+    pub fn get_data(&self, offset: u32, len: u32) -> WasmEdgeResult<Vec<u8>> {
+        self.mem.get_data(offset, len)
+    }
+
+    /// Returns the const data pointer to the [Memory]. See [Memory::data_pointer].
+    pub fn data_pointer(&self, offset: u32, len: u32) -> WasmEdgeResult<&'a u8> {
+        self.mem.data_pointer(offset, len)
+    }
+
+    /// Reads an unsigned little-endian integer of `nbytes` bytes from the [Memory]. See [Memory::read_uint].
+    pub fn read_uint(&self, offset: u32, nbytes: u8) -> WasmEdgeResult<u64> {
+        self.mem.read_uint(offset, nbytes)
+    }
+
+    /// Reads a signed little-endian integer of `nbytes` bytes from the [Memory]. See [Memory::read_int].
+    pub fn read_int(&self, offset: u32, nbytes: u8) -> WasmEdgeResult<i64> {
+        self.mem.read_int(offset, nbytes)
+    }
+
+    /// Reads a string of little-endian UTF-16 code units from the [Memory]. See [Memory::read_utf16].
+    pub fn read_utf16(&self, offset: u32, units: u32) -> WasmEdgeResult<String> {
+        self.mem.read_utf16(offset, units)
+    }
+
+    /// Feeds the data in a range of the [Memory] to the given sink in pieces. See [Memory::stream_region].
+    pub fn stream_region(
+        &self,
+        offset: u32,
+        len: u32,
+        chunk_size: u32,
+        sink: impl FnMut(&[u8]) -> std::io::Result<()>,
+    ) -> WasmEdgeResult<()> {
+        self.mem.stream_region(offset, len, chunk_size, sink)
+    }
+
+    /// Returns the offset of the first nonzero byte in a range of the [Memory]. See [Memory::find_nonzero].
+    pub fn find_nonzero(&self, offset: u32, len: u32) -> WasmEdgeResult<Option<u32>> {
+        self.mem.find_nonzero(offset, len)
+    }
+}
+
+/// The size of a WebAssembly page in bytes.
+const PAGE_SIZE: u32 = 65536;
+
+/// Marker trait for the plain-old-data types which can be viewed in place in a [Memory].
+///
+/// # Safety
+///
+/// Any bit pattern of the size of the implementor must be a valid value of it.
+pub unsafe trait Pod: Copy {}
+unsafe impl Pod for u8 {}
+unsafe impl Pod for i8 {}
+unsafe impl Pod for u16 {}
+unsafe impl Pod for i16 {}
+unsafe impl Pod for u32 {}
+unsafe impl Pod for i32 {}
+unsafe impl Pod for u64 {}
+unsafe impl Pod for i64 {}
+unsafe impl Pod for f32 {}
+unsafe impl Pod for f64 {}
+
+/// Struct of a typed region registered in a [Memory].
+///
+/// A [RegionHandle] is created by [Memory::register_region], and produces the views of the region on demand.
+#[derive(Debug, Clone, Copy)]
+pub struct RegionHandle<T: Pod> {
+    offset: u32,
+    count: u32,
+    _marker: PhantomData<T>,
+}
+impl<T: Pod> RegionHandle<T> {
+    /// Returns the start offset of the region in the [Memory].
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// Returns the number of the elements in the region.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Returns a view of the region in the given [Memory].
+    ///
+    /// # Errors
+    ///
+    /// If the region is out of the bounds of the [Memory], or the start address is not aligned to `T`, then an error
+    /// is returned.
+    pub fn as_slice<'a>(&self, mem: &'a Memory) -> WasmEdgeResult<&'a [T]> {
+        let ptr = mem.raw_pointer(self.offset, self.byte_len()?)?;
+        if ptr as usize % std::mem::align_of::<T>() != 0 {
+            return Err(WasmEdgeError::Mem(MemError::Unaligned));
+        }
+        Ok(unsafe { std::slice::from_raw_parts(ptr as *const T, self.count as usize) })
+    }
+
+    /// Returns a mutable view of the region in the given [Memory].
+    ///
+    /// # Errors
+    ///
+    /// If the region is out of the bounds of the [Memory], or the start address is not aligned to `T`, then an error
+    /// is returned.
+    pub fn as_mut_slice<'a>(&self, mem: &'a mut Memory) -> WasmEdgeResult<&'a mut [T]> {
+        let len = self.byte_len()?;
+        let ptr = mem.raw_pointer_mut(self.offset, len)?;
+        if ptr as usize % std::mem::align_of::<T>() != 0 {
+            return Err(WasmEdgeError::Mem(MemError::Unaligned));
+        }
+        Ok(unsafe { std::slice::from_raw_parts_mut(ptr as *mut T, self.count as usize) })
+    }
+
+    fn byte_len(&self) -> WasmEdgeResult<u32> {
+        let len = self.count as u64 * std::mem::size_of::<T>() as u64;
+        u32::try_from(len).map_err(|_| {
+            WasmEdgeError::Mem(MemError::LengthOverflow {
+                offset: self.offset,
+                len,
+            })
+        })
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct InnerMemory(pub(crate) *mut ffi::WasmEdge_MemoryInstanceContext);
+unsafe impl Send for InnerMemory {}
+unsafe impl Sync for InnerMemory {}
+
+/// Struct of WasmEdge MemType.
+///
+/// A [MemType] classifies a [Memory] and its size range.
+#[derive(Debug)]
+pub struct MemType {
+    pub(crate) inner: InnerMemType,
+    pub(crate) registered: bool,
+}
+impl MemType {
+    /// Create a new [MemType] to be associated with the given limit range for the capacity.
+    ///
+    /// # Arguments
+    ///
+    /// - `limit` specifies the linear memory size. The start value of the limit range specifies the min size (also, initial size) of the memory, while the end value specifies the max size allowed to grow. The maximum size is `u32::MAX`.
+    ///
+    /// # Errors
+    ///
+    /// If fail to create a [MemType], then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let ty = MemType::create(0..=u32::MAX);
+    /// ```
+    ///
+    pub fn create(limit: RangeInclusive<u32>) -> WasmEdgeResult<Self> {
+        Self::from_limit(ffi::WasmEdge_Limit::from(limit))
+    }
+
+    /// Creates a new [MemType] with the given raw limit, which keeps a max size equal to the min size.
+    pub(crate) fn from_limit(limit: ffi::WasmEdge_Limit) -> WasmEdgeResult<Self> {
+        let ctx = unsafe { ffi::WasmEdge_MemoryTypeCreate(limit) };
+        match ctx.is_null() {
+            true => Err(WasmEdgeError::MemTypeCreate),
+            false => Ok(Self {
+                inner: InnerMemType(ctx),
+                registered: false,
+            }),
+        }
+    }
+
+    /// Returns the limit range of a [MemType].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use wasmedge_sys::MemType;
+    ///
+    /// let ty = MemType::create(0..=u32::MAX).expect("fail to create a MemType");
+    /// assert_eq!(ty.limit(), 0..=u32::MAX);
+    /// ```
+    ///
+    pub fn limit(&self) -> RangeInclusive<u32> {
+        let limit = unsafe { ffi::WasmEdge_MemoryTypeGetLimit(self.inner.0) };
+        RangeInclusive::from(limit)
+    }
+}
+impl Drop for MemType {
+    fn drop(&mut self) {
+        if !self.registered && !self.inner.0.is_null() {
+            unsafe { ffi::WasmEdge_MemoryTypeDelete(self.inner.0) }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct InnerMemType(pub(crate) *mut ffi::WasmEdge_MemoryTypeContext);
+unsafe impl Send for InnerMemType {}
+unsafe impl Sync for InnerMemType {}
+
+#[cfg(test)]
 mod tests {
     use super::*;
-    use crate::error::{CoreError, CoreExecutionError, WasmEdgeError};
     use std::{
         sync::{Arc, Mutex},
         thread,
@@ -430,7 +1090,11 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(
             result.unwrap_err(),
-            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::MemoryOutOfBounds))
+            WasmEdgeError::Mem(MemError::OutOfBounds {
+                offset: u32::pow(2, 16) - 9,
+                len: 10,
+                byte_len: u64::pow(2, 16),
+            })
         );
 
         // grow the memory size
@@ -441,6 +1105,603 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_memory_get_data_large() {
+        // create a Memory: the min size 2 and the max size 3
+        let result = MemType::create(2..=3);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Memory::create(&ty);
+        assert!(result.is_ok());
+        let mut mem = result.unwrap();
+
+        // fill the whole memory
+        let len = 2 * u32::pow(2, 16);
+        let data = (0..len).map(|x| x as u8).collect::<Vec<_>>();
+        let result = mem.set_data(data.clone(), 0);
+        assert!(result.is_ok());
+
+        // read the whole memory back
+        let result = mem.get_data(0, len);
+        assert!(result.is_ok());
+        let returned = result.unwrap();
+        assert_eq!(returned.len(), len as usize);
+        assert_eq!(returned.capacity(), len as usize);
+        assert_eq!(returned, data);
+
+        // read a range in the middle
+        let result = mem.get_data(len / 2 - 5, 10);
+        assert!(result.is_ok());
+        assert_eq!(
+            result.unwrap(),
+            data[(len / 2 - 5) as usize..(len / 2 + 5) as usize]
+        );
+    }
+
+    #[test]
+    fn test_memory_read_int() {
+        // create a Memory: the min size 1 and the max size 2
+        let result = MemType::create(1..=2);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Memory::create(&ty);
+        assert!(result.is_ok());
+        let mut mem = result.unwrap();
+
+        // set a 3-byte value
+        let result = mem.set_data(vec![0x01, 0x02, 0xFF], 10);
+        assert!(result.is_ok());
+
+        // read the value as an unsigned integer
+        let result = mem.read_uint(10, 3);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), 0xFF0201);
+
+        // read the value as a signed integer
+        let result = mem.read_int(10, 3);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), 0xFF0201 - 0x1000000);
+
+        // read the full width
+        let result = mem.set_data(vec![0xFF; 8], 20);
+        assert!(result.is_ok());
+        let result = mem.read_uint(20, 8);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), u64::MAX);
+        let result = mem.read_int(20, 8);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), -1);
+
+        // invalid width
+        let result = mem.read_uint(10, 0);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Mem(MemError::InvalidByteWidth(0))
+        );
+        let result = mem.read_int(10, 9);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Mem(MemError::InvalidByteWidth(9))
+        );
+
+        // out of bounds
+        let result = mem.read_uint(u32::pow(2, 16) - 2, 3);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Mem(MemError::OutOfBounds {
+                offset: u32::pow(2, 16) - 2,
+                len: 3,
+                byte_len: u64::pow(2, 16),
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "compression")]
+    fn test_memory_snapshot_compressed() {
+        // create a Memory with 4 pages
+        let result = MemType::create(4..=8);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Memory::create(&ty);
+        assert!(result.is_ok());
+        let mut mem = result.unwrap();
+
+        // write a few bytes into the mostly-zero memory
+        let result = mem.set_data(b"hello".iter().copied(), 10);
+        assert!(result.is_ok());
+        let result = mem.set_data(vec![0xFF; 16], 3 * u32::pow(2, 16) + 100);
+        assert!(result.is_ok());
+        let result = mem.get_data(0, 4 * u32::pow(2, 16));
+        assert!(result.is_ok());
+        let original = result.unwrap();
+
+        // take a compressed snapshot
+        let result = mem.snapshot_compressed();
+        assert!(result.is_ok());
+        let snapshot = result.unwrap();
+        assert!(snapshot.len() < 1024);
+
+        // restore the snapshot into a new memory with 1 page
+        let result = MemType::create(1..=8);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Memory::create(&ty);
+        assert!(result.is_ok());
+        let mut restored = result.unwrap();
+        let result = restored.restore_compressed(&snapshot);
+        assert!(result.is_ok());
+        assert_eq!(restored.size(), 4);
+        let result = restored.get_data(0, 4 * u32::pow(2, 16));
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), original);
+
+        // restore invalid data
+        let result = restored.restore_compressed(b"not a snapshot");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_memory_read_endian() {
+        // create a Memory: the min size 1 and the max size 2
+        let result = MemType::create(1..=2);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Memory::create(&ty);
+        assert!(result.is_ok());
+        let mut mem = result.unwrap();
+
+        // set 8 bytes
+        let result = mem.set_data(vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0xFF], 0);
+        assert!(result.is_ok());
+
+        // read the same bytes in both byte orders
+        assert_eq!(mem.read_u32_le(0).unwrap(), 0x04030201);
+        assert_eq!(mem.read_u32_be(0).unwrap(), 0x01020304);
+        assert_eq!(mem.read_i32_le(4).unwrap(), 0xFF070605_u32 as i32);
+        assert_eq!(mem.read_i32_be(4).unwrap(), 0x050607FF);
+        assert_eq!(mem.read_u64_le(0).unwrap(), 0xFF07060504030201);
+        assert_eq!(mem.read_u64_be(0).unwrap(), 0x01020304050607FF);
+        assert_eq!(mem.read_i64_le(0).unwrap(), 0xFF07060504030201_u64 as i64);
+        assert_eq!(mem.read_i64_be(0).unwrap(), 0x01020304050607FF);
+
+        // read out of bounds
+        let result = mem.read_u64_be(u32::pow(2, 16) - 4);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_memory_read_utf16() {
+        // create a Memory: the min size 1 and the max size 2
+        let result = MemType::create(1..=2);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Memory::create(&ty);
+        assert!(result.is_ok());
+        let mut mem = result.unwrap();
+
+        // set a UTF-16LE string including a character encoded as a surrogate pair
+        let text = "h\u{e9}llo \u{1F600}";
+        let data = text
+            .encode_utf16()
+            .flat_map(|unit| unit.to_le_bytes())
+            .collect::<Vec<u8>>();
+        let units = (data.len() / 2) as u32;
+        assert_eq!(units, 8);
+        let result = mem.set_data(data, 0);
+        assert!(result.is_ok());
+
+        // read the string
+        let result = mem.read_utf16(0, units);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), text);
+
+        // read the string without the low surrogate
+        let result = mem.read_utf16(0, units - 1);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Mem(MemError::InvalidUtf16(0xD83D))
+        );
+
+        // read out of bounds
+        let result = mem.read_utf16(u32::pow(2, 16) - 2, 2);
+        assert!(result.is_err());
+
+        // the length in bytes overflows u32
+        let result = mem.read_utf16(0, u32::MAX);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Mem(MemError::LengthOverflow {
+                offset: 0,
+                len: u32::MAX as u64 * 2,
+            })
+        );
+    }
+
+    #[test]
+    fn test_memory_would_relocate_on_grow() {
+        // create a Memory: the min size 1 and the max size 2
+        let result = MemType::create(1..=2);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Memory::create(&ty);
+        assert!(result.is_ok());
+        let mut mem = result.unwrap();
+
+        // growing by zero pages never relocates
+        assert!(!mem.would_relocate_on_grow(0));
+
+        // growing beyond the max size is assumed to relocate
+        assert!(mem.would_relocate_on_grow(2));
+
+        #[cfg(any(
+            target_arch = "x86_64",
+            target_arch = "aarch64",
+            all(windows, target_pointer_width = "64")
+        ))]
+        {
+            // growing within the reserved capacity keeps the data in place
+            assert!(!mem.would_relocate_on_grow(1));
+            let result = mem.data_pointer(0, 1);
+            assert!(result.is_ok());
+            let ptr = result.unwrap() as *const u8;
+            let result = mem.grow(1);
+            assert!(result.is_ok());
+            let result = mem.data_pointer(0, 1);
+            assert!(result.is_ok());
+            assert_eq!(result.unwrap() as *const u8, ptr);
+
+            // the memory has reached its max size
+            assert!(mem.would_relocate_on_grow(1));
+        }
+
+        #[cfg(not(any(
+            target_arch = "x86_64",
+            target_arch = "aarch64",
+            all(windows, target_pointer_width = "64")
+        )))]
+        {
+            // the data may be reallocated when growing
+            assert!(mem.would_relocate_on_grow(1));
+            let result = mem.grow(1);
+            assert!(result.is_ok());
+        }
+    }
+
+    #[test]
+    fn test_memory_ensure_capacity() {
+        // create a Memory: the min size 1 and the max size 3
+        let result = MemType::create(1..=3);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Memory::create(&ty);
+        assert!(result.is_ok());
+        let mut mem = result.unwrap();
+        let page_size = u64::pow(2, 16);
+
+        // the memory is large enough
+        let result = mem.ensure_capacity(page_size);
+        assert!(result.is_ok());
+        assert_eq!(mem.size(), 1);
+
+        // the byte count crosses the page boundary
+        let result = mem.ensure_capacity(page_size + 1);
+        assert!(result.is_ok());
+        assert_eq!(mem.size(), 2);
+        let result = mem.set_data(vec![1], page_size as u32);
+        assert!(result.is_ok());
+
+        // exceed the max size
+        let result = mem.ensure_capacity(3 * page_size + 1);
+        assert!(result.is_err());
+        assert_eq!(mem.size(), 2);
+
+        // exceed the 32-bit address space
+        let result = mem.ensure_capacity(u64::MAX);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Mem(MemError::CapacityOverflow(u64::MAX))
+        );
+        assert_eq!(mem.size(), 2);
+    }
+
+    #[test]
+    fn test_memory_error_context() {
+        // create a Memory: the min size 1 and the max size 2
+        let result = MemType::create(1..=2);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Memory::create(&ty);
+        assert!(result.is_ok());
+        let mut mem = result.unwrap();
+        let byte_len = u64::pow(2, 16);
+        let offset = u32::pow(2, 16) - 4;
+
+        // get data out of bounds
+        let result = mem.get_data(offset, 8);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Mem(MemError::OutOfBounds {
+                offset,
+                len: 8,
+                byte_len
+            })
+        );
+
+        // set data out of bounds
+        let result = mem.set_data(vec![0; 8], offset);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Mem(MemError::OutOfBounds {
+                offset,
+                len: 8,
+                byte_len
+            })
+        );
+
+        // get the const pointer out of bounds
+        let result = mem.data_pointer(offset, 8);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Mem(MemError::ConstPtr {
+                offset,
+                len: 8,
+                byte_len
+            })
+        );
+
+        // get the mutable pointer out of bounds
+        let result = mem.data_pointer_mut(offset, 8);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Mem(MemError::MutPtr {
+                offset,
+                len: 8,
+                byte_len
+            })
+        );
+
+        // the memory size in the error follows the growth of the memory
+        let result = mem.grow(1);
+        assert!(result.is_ok());
+        let result = mem.get_data(2 * offset, 16);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Mem(MemError::OutOfBounds {
+                offset: 2 * offset,
+                len: 16,
+                byte_len: 2 * byte_len
+            })
+        );
+    }
+
+    #[test]
+    fn test_memory_stream_region() {
+        // create a Memory: the min size 1 and the max size 2
+        let result = MemType::create(1..=2);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Memory::create(&ty);
+        assert!(result.is_ok());
+        let mut mem = result.unwrap();
+
+        // set data
+        let data = (0..=255u8).cycle().take(10000).collect::<Vec<u8>>();
+        let result = mem.set_data(data, 100);
+        assert!(result.is_ok());
+
+        // stream the region into a Vec
+        let mut streamed = Vec::new();
+        let mut chunks = 0;
+        let result = mem.stream_region(100, 10000, 4096, |chunk| {
+            assert!(chunk.len() <= 4096);
+            chunks += 1;
+            streamed.extend_from_slice(chunk);
+            Ok(())
+        });
+        assert!(result.is_ok());
+        assert_eq!(chunks, 3);
+        let result = mem.get_data(100, 10000);
+        assert!(result.is_ok());
+        assert_eq!(streamed, result.unwrap());
+
+        // the sink fails
+        let result = mem.stream_region(100, 10000, 4096, |_| {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "sink closed",
+            ))
+        });
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Mem(MemError::Io(String::from("sink closed")))
+        );
+
+        // stream a region out of bounds
+        let result = mem.stream_region(u32::pow(2, 16) - 4, 8, 4096, |_| Ok(()));
+        assert!(result.is_err());
+
+        // stream with a zero chunk size
+        let result = mem.stream_region(0, 8, 0, |_| Ok(()));
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Mem(MemError::ZeroChunkSize)
+        );
+    }
+
+    #[test]
+    fn test_memory_dirty_pages_since() {
+        // create a Memory: the min size 4 and the max size 5
+        let result = MemType::create(4..=5);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Memory::create(&ty);
+        assert!(result.is_ok());
+        let mut mem = result.unwrap();
+        let page_size = u32::pow(2, 16);
+
+        // take a baseline
+        let result = mem.get_data(0, 4 * page_size);
+        assert!(result.is_ok());
+        let baseline = result.unwrap();
+        let result = mem.dirty_pages_since(&baseline);
+        assert!(result.is_ok());
+        assert!(result.unwrap().is_empty());
+
+        // modify bytes in the page 1 and the page 3
+        let result = mem.set_data(vec![1, 2, 3], page_size + 100);
+        assert!(result.is_ok());
+        let result = mem.set_data(vec![4], 4 * page_size - 1);
+        assert!(result.is_ok());
+        let result = mem.dirty_pages_since(&baseline);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), vec![1, 3]);
+
+        // the page added by growing is not covered by the baseline
+        let result = mem.grow(1);
+        assert!(result.is_ok());
+        let result = mem.dirty_pages_since(&baseline);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), vec![1, 3, 4]);
+    }
+
+    #[test]
+    fn test_memory_as_ref() {
+        // create a Memory: the min size 1 and the max size 2
+        let result = MemType::create(1..=2);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Memory::create(&ty);
+        assert!(result.is_ok());
+        let mut mem = result.unwrap();
+        let result = mem.set_data(vec![0x68, 0x00, 0x69, 0x00], 100);
+        assert!(result.is_ok());
+
+        // read the data through the read-only view
+        let mem_ref = mem.as_ref();
+        assert_eq!(mem_ref.size(), 1);
+        let result = mem_ref.ty();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().limit(), 1..=2);
+        let result = mem_ref.get_data(100, 4);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), vec![0x68, 0x00, 0x69, 0x00]);
+        let result = mem_ref.read_uint(100, 2);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), 0x68);
+        let result = mem_ref.read_utf16(100, 2);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "hi");
+        let result = mem_ref.find_nonzero(0, 200);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), Some(100));
+
+        // read out of bounds
+        let result = mem_ref.get_data(u32::pow(2, 16) - 2, 4);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_memory_find_nonzero() {
+        // create a Memory: the min size 1 and the max size 2
+        let result = MemType::create(1..=2);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Memory::create(&ty);
+        assert!(result.is_ok());
+        let mut mem = result.unwrap();
+
+        // all zero
+        let result = mem.find_nonzero(0, u32::pow(2, 16));
+        assert!(result.is_ok());
+        assert!(result.unwrap().is_none());
+
+        // set a single nonzero byte in the middle of the region
+        let result = mem.set_data(vec![0x2a], 1500);
+        assert!(result.is_ok());
+        let result = mem.find_nonzero(1000, 1000);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), Some(1500));
+
+        // the byte is out of the region
+        let result = mem.find_nonzero(1501, 1000);
+        assert!(result.is_ok());
+        assert!(result.unwrap().is_none());
+
+        // scan out of bounds
+        let result = mem.find_nonzero(u32::pow(2, 16) - 4, 8);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_memory_region() {
+        // create a Memory: the min size 1 and the max size 2
+        let result = MemType::create(1..=2);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Memory::create(&ty);
+        assert!(result.is_ok());
+        let mut mem = result.unwrap();
+
+        // register a region of four i32 values
+        let result = mem.register_region::<i32>(16, 4);
+        assert!(result.is_ok());
+        let region = result.unwrap();
+        assert_eq!(region.offset(), 16);
+        assert_eq!(region.count(), 4);
+
+        // write through the region
+        let result = region.as_mut_slice(&mut mem);
+        assert!(result.is_ok());
+        result.unwrap().copy_from_slice(&[1, -2, 3, -4]);
+
+        // grow the memory
+        let result = mem.grow(1);
+        assert!(result.is_ok());
+        assert_eq!(mem.size(), 2);
+
+        // read through the region after growing
+        let result = region.as_slice(&mem);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), &[1, -2, 3, -4]);
+        let result = mem.get_data(16, 4);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), 1i32.to_le_bytes());
+
+        // register a region out of bounds
+        let result = mem.register_region::<i32>(u32::pow(2, 17) - 4, 2);
+        assert!(result.is_err());
+
+        // the length in bytes of the region overflows u32
+        let result = mem.register_region::<i32>(16, u32::MAX);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Mem(MemError::LengthOverflow {
+                offset: 16,
+                len: u32::MAX as u64 * 4,
+            })
+        );
+
+        // register an unaligned region
+        let result = mem.register_region::<i32>(2, 1);
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), WasmEdgeError::Mem(MemError::Unaligned));
+    }
+
     #[test]
     fn test_memory_send() {
         {
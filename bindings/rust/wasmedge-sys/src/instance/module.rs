@@ -1,11 +1,16 @@
 //! Defines WasmEdge Instancestruct.
 
 use crate::{
-    error::{InstanceError, WasmEdgeError},
+    error::{check, GlobalError, InstanceError, WasmEdgeError},
     ffi,
     instance::{function::InnerFunc, global::InnerGlobal, memory::InnerMemory, table::InnerTable},
     types::WasmEdgeString,
-    Function, Global, Memory, Store, Table, WasmEdgeResult,
+    Executor, Function, Global, Memory, Mutability, Store, Table, ValType, WasmEdgeResult,
+    WasmValue, HOST_STATES,
+};
+use std::{
+    any::{Any, TypeId},
+    sync::{Arc, Mutex},
 };
 
 /// Struct of WasmEdge Instance.
@@ -152,6 +157,346 @@ impl<'store> Instance<'store> {
         }
     }
 
+    /// Returns the value type of the exported [global](crate::Global) in this module by the given global name.
+    ///
+    /// # Argument
+    ///
+    /// - `name` specifies the target exported [global](crate::Global) instance.
+    ///
+    /// # Error
+    ///
+    /// If fail to find the target [global](crate::Global) or its type, then an error is returned.
+    pub fn global_value_type(&self, name: impl AsRef<str>) -> WasmEdgeResult<ValType> {
+        let ty_ctx = self.global_type_ctx(name.as_ref())?;
+        let val_ty = unsafe { ffi::WasmEdge_GlobalTypeGetValType(ty_ctx) };
+        Ok(val_ty.into())
+    }
+
+    /// Checks if the exported [global](crate::Global) in this module is mutable by the given global name.
+    ///
+    /// # Argument
+    ///
+    /// - `name` specifies the target exported [global](crate::Global) instance.
+    ///
+    /// # Error
+    ///
+    /// If fail to find the target [global](crate::Global) or its type, then an error is returned.
+    pub fn global_is_mutable(&self, name: impl AsRef<str>) -> WasmEdgeResult<bool> {
+        let ty_ctx = self.global_type_ctx(name.as_ref())?;
+        let mutability: Mutability =
+            unsafe { ffi::WasmEdge_GlobalTypeGetMutability(ty_ctx) }.into();
+        Ok(mutability == Mutability::Var)
+    }
+
+    /// Returns the type context of the exported global, which is owned by the global instance.
+    fn global_type_ctx(
+        &self,
+        name: &str,
+    ) -> WasmEdgeResult<*const ffi::WasmEdge_GlobalTypeContext> {
+        let global = self.find_global(name)?;
+        let ty_ctx = unsafe { ffi::WasmEdge_GlobalInstanceGetGlobalType(global.inner.0) };
+        match ty_ctx.is_null() {
+            true => Err(WasmEdgeError::Global(GlobalError::Type)),
+            false => Ok(ty_ctx),
+        }
+    }
+
+    /// Returns the value of the exported `__stack_pointer` [global](crate::Global) in this module, or `None` if the
+    /// module does not export it.
+    ///
+    /// Many toolchains, such as LLVM, keep the stack pointer of the guest in a mutable global named `__stack_pointer`,
+    /// so its value helps to correlate a trap with the stack usage.
+    ///
+    /// # Error
+    ///
+    /// If the `__stack_pointer` global is not of the `i32` type, then an error is returned.
+    pub fn stack_pointer(&self) -> WasmEdgeResult<Option<u32>> {
+        let global = match self.find_global("__stack_pointer") {
+            Ok(global) => global,
+            Err(WasmEdgeError::Instance(InstanceError::NotFoundGlobal(_))) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+
+        let value = global.get_value();
+        match value.ty() {
+            ValType::I32 => Ok(Some(value.to_i32() as u32)),
+            _ => Err(WasmEdgeError::Global(GlobalError::UnmatchedValType)),
+        }
+    }
+
+    /// Returns the version or ABI marker exported by this module, or `None` if the module does not export it.
+    ///
+    /// Guests often export a version marker, such as `__abi_version`, either as an integer
+    /// [global](crate::Global) or as a function without parameters which returns an integer. Checking it before
+    /// calling other functions lets the host reject the guests with an incompatible ABI.
+    ///
+    /// # Argument
+    ///
+    /// - `export_name` specifies the name of the exported [global](crate::Global) or [function](crate::Function).
+    ///
+    /// # Error
+    ///
+    /// If the export is neither an `i32`/`i64` global nor a function without parameters returning a single `i32`/`i64`
+    /// value, or fail to invoke the function, then an error is returned.
+    pub fn abi_version(&self, export_name: impl AsRef<str>) -> WasmEdgeResult<Option<i64>> {
+        let name = export_name.as_ref();
+        let to_version = |value: WasmValue| match value.ty() {
+            ValType::I32 => Ok(Some(value.to_i32() as i64)),
+            ValType::I64 => Ok(Some(value.to_i64())),
+            _ => Err(WasmEdgeError::Instance(InstanceError::InvalidAbiVersion(
+                name.to_string(),
+            ))),
+        };
+
+        // read the version from the global
+        match self.find_global(name) {
+            Ok(global) => return to_version(global.get_value()),
+            Err(WasmEdgeError::Instance(InstanceError::NotFoundGlobal(_))) => {}
+            Err(e) => return Err(e),
+        }
+
+        // get the version by calling the function
+        let func = match self.find_func(name) {
+            Ok(func) => func,
+            Err(WasmEdgeError::Instance(InstanceError::NotFoundFunc(_))) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        let ty = func.ty()?;
+        if ty.params_len() != 0 || ty.returns_len() != 1 {
+            return Err(WasmEdgeError::Instance(InstanceError::InvalidAbiVersion(
+                name.to_string(),
+            )));
+        }
+
+        let mut returns = self.invoke(name, [])?;
+        to_version(returns.remove(0))
+    }
+
+    /// Invokes the exported function which returns a `(ptr, len)` buffer allocated in the exported `memory` of this
+    /// module, copies the buffer out, and then frees it by calling the exported `free` function with `ptr`.
+    ///
+    /// The `free` function is called even if fail to read the buffer, so that the guest memory does not leak.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` specifies the name of the function returning the `(ptr, len)` buffer, both of the `i32` type.
+    ///
+    /// - `params` specifies the argument values for the function.
+    ///
+    /// - `free_name` specifies the name of the function which takes `ptr` and frees the buffer.
+    ///
+    /// # Error
+    ///
+    /// If fail to invoke the functions, or the function does not return a `(ptr, len)` pair, or fail to read the
+    /// buffer from the `memory`, then an error is returned.
+    pub fn call_with_free(
+        &self,
+        name: impl AsRef<str>,
+        params: impl IntoIterator<Item = WasmValue>,
+        free_name: impl AsRef<str>,
+    ) -> WasmEdgeResult<Vec<u8>> {
+        let returns = self.invoke(name.as_ref(), params)?;
+        let (ptr, len) = match returns[..] {
+            [ptr, len] if ptr.ty() == ValType::I32 && len.ty() == ValType::I32 => {
+                (ptr.to_i32() as u32, len.to_i32() as u32)
+            }
+            _ => {
+                return Err(WasmEdgeError::Instance(
+                    InstanceError::UnmatchedBufferReturns(name.as_ref().to_string()),
+                ))
+            }
+        };
+
+        let data = self
+            .find_memory("memory")
+            .and_then(|mem| mem.get_data(ptr, len));
+        self.invoke(free_name.as_ref(), [WasmValue::from_i32(ptr as i32)])?;
+        data
+    }
+
+    /// Invokes the exported function with a scratch buffer allocated in the guest, and frees the buffer after the
+    /// invocation.
+    ///
+    /// The scratch buffer is allocated by calling the exported `malloc` function with `scratch_len`, and freed by
+    /// calling the exported `free` function with the pointer returned by `malloc`. The function is invoked with
+    /// `(scratch_ptr, scratch_len)` as the first two arguments, followed by `extra_params`. The buffer is freed even if
+    /// the invocation fails.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` specifies the name of the function to invoke.
+    ///
+    /// - `scratch_len` specifies the length in bytes of the scratch buffer.
+    ///
+    /// - `extra_params` specifies the argument values following the scratch buffer.
+    ///
+    /// # Error
+    ///
+    /// If fail to allocate the scratch buffer, or fail to invoke the functions, then an error is returned.
+    pub fn call_with_scratch(
+        &self,
+        name: impl AsRef<str>,
+        scratch_len: u32,
+        extra_params: &[WasmValue],
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        let returns = self.invoke("malloc", [WasmValue::from_i32(scratch_len as i32)])?;
+        let scratch_ptr = match returns[..] {
+            [ptr] if ptr.ty() == ValType::I32 && ptr.to_i32() != 0 => ptr,
+            _ => {
+                return Err(WasmEdgeError::Instance(InstanceError::ScratchAlloc(
+                    scratch_len,
+                )))
+            }
+        };
+
+        let params = [scratch_ptr, WasmValue::from_i32(scratch_len as i32)]
+            .into_iter()
+            .chain(extra_params.iter().copied());
+        let returns = self.invoke(name.as_ref(), params);
+        self.invoke("free", [scratch_ptr])?;
+        returns
+    }
+
+    /// Invokes the exported function of this module with a temporary [Executor](crate::Executor).
+    fn invoke(
+        &self,
+        func_name: &str,
+        params: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        let returns_len = self.find_func(func_name)?.ty()?.returns_len();
+        let raw_params = params.into_iter().map(|x| x.as_raw()).collect::<Vec<_>>();
+        let mut returns = Vec::with_capacity(returns_len);
+
+        let executor = Executor::create(None, None)?;
+        let raw_func_name: WasmEdgeString = func_name.into();
+        unsafe {
+            match self.name() {
+                Some(mod_name) => {
+                    let mod_name: WasmEdgeString = mod_name.as_str().into();
+                    check(ffi::WasmEdge_ExecutorInvokeRegistered(
+                        executor.inner.0,
+                        self.store.inner.0,
+                        mod_name.as_raw(),
+                        raw_func_name.as_raw(),
+                        raw_params.as_ptr(),
+                        raw_params.len() as u32,
+                        returns.as_mut_ptr(),
+                        returns_len as u32,
+                    ))?;
+                }
+                None => {
+                    check(ffi::WasmEdge_ExecutorInvoke(
+                        executor.inner.0,
+                        self.store.inner.0,
+                        raw_func_name.as_raw(),
+                        raw_params.as_ptr(),
+                        raw_params.len() as u32,
+                        returns.as_mut_ptr(),
+                        returns_len as u32,
+                    ))?;
+                }
+            }
+            returns.set_len(returns_len);
+        }
+
+        Ok(returns.into_iter().map(Into::into).collect::<Vec<_>>())
+    }
+
+    /// Attaches a host state to the module instance. If a state of the same type has been attached, it is replaced.
+    ///
+    /// The states are kept in a type map, so at most one state of each type can be attached to a module instance. The
+    /// host functions called by the module instance access the states through the [Caller](crate::Caller), see
+    /// [Caller::host_state_mut](crate::Caller::host_state_mut).
+    ///
+    /// # Argument
+    ///
+    /// - `state` specifies the host state to attach.
+    ///
+    /// # Error
+    ///
+    /// If the module instance is the active module instance, which has no name, then an error is returned.
+    ///
+    /// # Notice
+    ///
+    /// The states are kept by the [Store] holding the module instance under the name of the module instance, and are
+    /// dropped together with the [Store] or the [Vm](crate::Vm) owning it, or by [Instance::remove_host_state].
+    ///
+    /// WasmEdge only passes the memory of the calling module to a host function, so the [Caller](crate::Caller) finds
+    /// the states through the exported memory of the module instance. The states of a module instance which exports no
+    /// memory are only accessible through the [Instance]. If several module instances export the same imported memory,
+    /// then the [Caller](crate::Caller) finds the states of the one which a state is attached to last.
+    pub fn set_host_state<T: Send + 'static>(&mut self, state: T) -> WasmEdgeResult<()> {
+        let mod_name = self
+            .name()
+            .ok_or(WasmEdgeError::Instance(InstanceError::UnnamedHostState))?;
+        let mem_addr = self
+            .mem_names()
+            .and_then(|names| names.into_iter().next())
+            .and_then(|name| self.find_memory(name).ok())
+            .map(|mem| mem.inner.0 as usize);
+
+        let state: Box<dyn Any + Send> = Box::new(state);
+        let mut all_states = HOST_STATES.lock().expect("[wasmedge-sys] try lock failed.");
+        let states = all_states.entry(self.store.inner.0 as usize).or_default();
+        if let Some(mem_addr) = mem_addr {
+            states.mem_owners.insert(mem_addr, mod_name.clone());
+        }
+        states
+            .states
+            .insert((mod_name, TypeId::of::<T>()), Arc::new(Mutex::new(state)));
+        Ok(())
+    }
+
+    /// Calls `f` with a reference to the host state of type `T` attached to the module instance, and returns the
+    /// result, or returns `None` if no state of the type is attached.
+    ///
+    /// The states are shared with the host functions which may run in other threads, so they are accessed under a lock
+    /// rather than by returning a reference. Accessing the same state again in `f` deadlocks.
+    pub fn host_state<T: Send + 'static, R>(&self, f: impl FnOnce(&T) -> R) -> Option<R> {
+        self.with_host_state(|state: &mut T| f(&*state))
+    }
+
+    /// Calls `f` with a mutable reference to the host state of type `T` attached to the module instance, and returns
+    /// the result, or returns `None` if no state of the type is attached. See [Instance::host_state].
+    pub fn host_state_mut<T: Send + 'static, R>(
+        &mut self,
+        f: impl FnOnce(&mut T) -> R,
+    ) -> Option<R> {
+        self.with_host_state(f)
+    }
+
+    /// Detaches the host state of type `T` from the module instance, and returns it, or returns `None` if no state of
+    /// the type is attached.
+    pub fn remove_host_state<T: Send + 'static>(&mut self) -> Option<T> {
+        let key = (self.name()?, TypeId::of::<T>());
+        let state = HOST_STATES
+            .lock()
+            .expect("[wasmedge-sys] try lock failed.")
+            .get_mut(&(self.store.inner.0 as usize))?
+            .states
+            .remove(&key)?;
+        let mut state = state.lock().expect("[wasmedge-sys] try lock failed.");
+        let state: Box<dyn Any + Send> = std::mem::replace(&mut *state, Box::new(()));
+        state.downcast::<T>().ok().map(|state| *state)
+    }
+
+    /// Calls `f` with the host state of type `T` attached to the module instance.
+    fn with_host_state<T: Send + 'static, R>(&self, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+        let key = (self.name()?, TypeId::of::<T>());
+        // release the lock of the map before locking the state, so that the other states can be accessed in `f`
+        let state = {
+            let all_states = HOST_STATES.lock().expect("[wasmedge-sys] try lock failed.");
+            Arc::clone(
+                all_states
+                    .get(&(self.store.inner.0 as usize))?
+                    .states
+                    .get(&key)?,
+            )
+        };
+        let mut state = state.lock().expect("[wasmedge-sys] try lock failed.");
+        state.downcast_mut::<T>().map(f)
+    }
+
     /// Returns the length of the exported [functions](crate::Function) in this module.
     pub fn func_len(&self) -> u32 {
         unsafe { ffi::WasmEdge_ModuleInstanceListFunctionLength(self.inner.0) }
@@ -273,16 +618,41 @@ impl<'store> Instance<'store> {
     }
 }
 
-#[derive(Debug)]
-pub(crate) struct InnerInstance(pub(crate) *const ffi::WasmEdge_ModuleInstanceContext);
-unsafe impl Send for InnerInstance {}
-unsafe impl Sync for InnerInstance {}
+/// Calls `f` with the host state of type `T` attached to the module instance of which the exported memory is at
+/// `mem_addr`.
+pub(crate) fn with_host_state<T: Send + 'static, R>(
+    mem_addr: usize,
+    f: impl FnOnce(&mut T) -> R,
+) -> Option<R> {
+    // release the lock of the map before locking the state, so that the other states can be accessed in `f`
+    let state = {
+        let all_states = HOST_STATES.lock().expect("[wasmedge-sys] try lock failed.");
+        all_states.values().find_map(|states| {
+            let mod_name = states.mem_owners.get(&mem_addr)?;
+            states
+                .states
+                .get(&(mod_name.clone(), TypeId::of::<T>()))
+                .map(Arc::clone)
+        })?
+    };
+    let mut state = state.lock().expect("[wasmedge-sys] try lock failed.");
+    state.downcast_mut::<T>().map(f)
+}
+
+/// Drops the host states attached to the module instances in the given [Store].
+pub(crate) fn drop_host_states(store: &Store) {
+    // drop the states after releasing the lock, in case that dropping a state accesses the other states
+    let _dropped = HOST_STATES
+        .lock()
+        .expect("[wasmedge-sys] try lock failed.")
+        .remove(&(store.inner.0 as usize));
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        Config, Executor, FuncType, GlobalType, ImportObject, MemType, Mutability, RefType,
+        Caller, Config, Executor, FuncType, GlobalType, ImportObject, MemType, Mutability, RefType,
         TableType, ValType, Vm, WasmValue,
     };
 
@@ -393,6 +763,423 @@ mod tests {
         assert_eq!(result.unwrap(), ["global"]);
     }
 
+    #[test]
+    fn test_instance_global_type_queries() {
+        let vm = create_vm();
+        let result = vm.store_mut();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+        let result = store.named_module("extern_module");
+        assert!(result.is_ok());
+        let instance = result.unwrap();
+
+        // check the exported const f32 global
+        let result = instance.global_value_type("global");
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), ValType::F32);
+        let result = instance.global_is_mutable("global");
+        assert!(result.is_ok());
+        assert!(!result.unwrap());
+
+        // the global does not exist
+        let result = instance.global_value_type("not_exist_global");
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Instance(InstanceError::NotFoundGlobal(String::from(
+                "not_exist_global"
+            )))
+        );
+        let result = instance.global_is_mutable("not_exist_global");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_instance_stack_pointer() {
+        let result = Store::create();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+
+        // create an ImportObject module exporting a "__stack_pointer" global
+        let result = ImportObject::create("with_sp");
+        assert!(result.is_ok());
+        let mut import = result.unwrap();
+        let result = GlobalType::create(ValType::I32, Mutability::Var);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Global::create(&ty, WasmValue::from_i32(65536));
+        assert!(result.is_ok());
+        let global = result.unwrap();
+        import.add_global("__stack_pointer", global);
+
+        // create an ImportObject module without a "__stack_pointer" global
+        let result = ImportObject::create("without_sp");
+        assert!(result.is_ok());
+        let mut import_without_sp = result.unwrap();
+        let result = GlobalType::create(ValType::I32, Mutability::Var);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Global::create(&ty, WasmValue::from_i32(1));
+        assert!(result.is_ok());
+        let global = result.unwrap();
+        import_without_sp.add_global("global", global);
+
+        // register the modules
+        let result = Executor::create(None, None);
+        assert!(result.is_ok());
+        let mut executor = result.unwrap();
+        let result = executor.register_import_object(&mut store, &import);
+        assert!(result.is_ok());
+        let result = executor.register_import_object(&mut store, &import_without_sp);
+        assert!(result.is_ok());
+
+        // read the stack pointer
+        let result = store.named_module("with_sp");
+        assert!(result.is_ok());
+        let instance = result.unwrap();
+        let result = instance.stack_pointer();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), Some(65536));
+
+        // no stack pointer
+        let result = store.named_module("without_sp");
+        assert!(result.is_ok());
+        let instance = result.unwrap();
+        let result = instance.stack_pointer();
+        assert!(result.is_ok());
+        assert!(result.unwrap().is_none());
+    }
+
+    #[test]
+    fn test_instance_abi_version() {
+        let result = Store::create();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+
+        // create an ImportObject module exporting a "__abi_version" global
+        let result = ImportObject::create("with_global");
+        assert!(result.is_ok());
+        let mut import_global = result.unwrap();
+        let result = GlobalType::create(ValType::I32, Mutability::Const);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Global::create(&ty, WasmValue::from_i32(2));
+        assert!(result.is_ok());
+        let global = result.unwrap();
+        import_global.add_global("__abi_version", global);
+
+        // create an ImportObject module exporting a "__abi_version" function
+        let result = ImportObject::create("with_func");
+        assert!(result.is_ok());
+        let mut import_func = result.unwrap();
+        let result = FuncType::create([], [ValType::I64]);
+        assert!(result.is_ok());
+        let func_ty = result.unwrap();
+        let result = Function::create(&func_ty, Box::new(real_abi_version), 0);
+        assert!(result.is_ok());
+        let host_func = result.unwrap();
+        import_func.add_func("__abi_version", host_func);
+
+        // create an ImportObject module without a "__abi_version" export
+        let result = ImportObject::create("without_version");
+        assert!(result.is_ok());
+        let mut import_without_version = result.unwrap();
+        let result = GlobalType::create(ValType::I32, Mutability::Var);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Global::create(&ty, WasmValue::from_i32(1));
+        assert!(result.is_ok());
+        let global = result.unwrap();
+        import_without_version.add_global("global", global);
+
+        // register the modules
+        let result = Executor::create(None, None);
+        assert!(result.is_ok());
+        let mut executor = result.unwrap();
+        let result = executor.register_import_object(&mut store, &import_global);
+        assert!(result.is_ok());
+        let result = executor.register_import_object(&mut store, &import_func);
+        assert!(result.is_ok());
+        let result = executor.register_import_object(&mut store, &import_without_version);
+        assert!(result.is_ok());
+
+        // read the version from the global
+        let result = store.named_module("with_global");
+        assert!(result.is_ok());
+        let instance = result.unwrap();
+        let result = instance.abi_version("__abi_version");
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), Some(2));
+
+        // read the version by calling the function
+        let result = store.named_module("with_func");
+        assert!(result.is_ok());
+        let instance = result.unwrap();
+        let result = instance.abi_version("__abi_version");
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), Some(3));
+
+        // no version marker
+        let result = store.named_module("without_version");
+        assert!(result.is_ok());
+        let instance = result.unwrap();
+        let result = instance.abi_version("__abi_version");
+        assert!(result.is_ok());
+        assert!(result.unwrap().is_none());
+    }
+
+    #[test]
+    fn test_instance_call_with_free() {
+        // (module
+        //   (memory (export "memory") 1)
+        //   (global $freed (export "freed") (mut i32) (i32.const 0))
+        //   (data (i32.const 16) "hello")
+        //   (func (export "greet") (result i32 i32) (i32.const 16) (i32.const 5))
+        //   (func (export "greet_oob") (result i32 i32) (i32.const 65534) (i32.const 5))
+        //   (func (export "free") (param i32) (global.set $freed (local.get 0))))
+        let wasm = [
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x0a, 0x02, 0x60, 0x00, 0x02,
+            0x7f, 0x7f, 0x60, 0x01, 0x7f, 0x00, 0x03, 0x04, 0x03, 0x00, 0x00, 0x01, 0x05, 0x03,
+            0x01, 0x00, 0x01, 0x06, 0x06, 0x01, 0x7f, 0x01, 0x41, 0x00, 0x0b, 0x07, 0x2d, 0x05,
+            0x06, 0x6d, 0x65, 0x6d, 0x6f, 0x72, 0x79, 0x02, 0x00, 0x05, 0x66, 0x72, 0x65, 0x65,
+            0x64, 0x03, 0x00, 0x05, 0x67, 0x72, 0x65, 0x65, 0x74, 0x00, 0x00, 0x09, 0x67, 0x72,
+            0x65, 0x65, 0x74, 0x5f, 0x6f, 0x6f, 0x62, 0x00, 0x01, 0x04, 0x66, 0x72, 0x65, 0x65,
+            0x00, 0x02, 0x0a, 0x18, 0x03, 0x06, 0x00, 0x41, 0x10, 0x41, 0x05, 0x0b, 0x08, 0x00,
+            0x41, 0xfe, 0xff, 0x03, 0x41, 0x05, 0x0b, 0x06, 0x00, 0x20, 0x00, 0x24, 0x00, 0x0b,
+            0x0b, 0x0b, 0x01, 0x00, 0x41, 0x10, 0x0b, 0x05, 0x68, 0x65, 0x6c, 0x6c, 0x6f,
+        ];
+
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+        let result = vm.register_wasm_from_buffer("guest", &wasm);
+        assert!(result.is_ok());
+        let result = vm.store_mut();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+        let result = store.named_module("guest");
+        assert!(result.is_ok());
+        let instance = result.unwrap();
+
+        // copy the buffer out and free it
+        let result = instance.call_with_free("greet", [], "free");
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), b"hello");
+        let result = instance.find_global("freed");
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().get_value().to_i32(), 16);
+
+        // the buffer is freed even if fail to read it
+        let result = instance.call_with_free("greet_oob", [], "free");
+        assert!(result.is_err());
+        let result = instance.find_global("freed");
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().get_value().to_i32(), 65534);
+
+        // the function does not return a buffer
+        let result = instance.call_with_free("free", [WasmValue::from_i32(0)], "free");
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Instance(InstanceError::UnmatchedBufferReturns(String::from("free")))
+        );
+    }
+
+    #[test]
+    fn test_instance_host_state() {
+        #[derive(Debug, PartialEq)]
+        struct Counter(i32);
+
+        // create an ImportObject with a host function which increments the counter of the calling module
+        let result = ImportObject::create("extern");
+        assert!(result.is_ok());
+        let mut import = result.unwrap();
+        let result = FuncType::create([], [ValType::I32]);
+        assert!(result.is_ok());
+        let func_ty = result.unwrap();
+        let result = Function::create(
+            &func_ty,
+            Box::new(|caller: &Caller, _inputs: Vec<WasmValue>| {
+                caller
+                    .host_state_mut(|counter: &mut Counter| {
+                        counter.0 += 1;
+                        vec![WasmValue::from_i32(counter.0)]
+                    })
+                    .ok_or(1u8)
+            }),
+            0,
+        );
+        assert!(result.is_ok());
+        import.add_func("incr", result.unwrap());
+
+        // (module
+        //   (import "extern" "incr" (func $incr (result i32)))
+        //   (memory (export "memory") 1)
+        //   (func (export "run") (result i32)
+        //     call $incr))
+        let wasm: &[u8] = &[
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x05, 0x01, 0x60, 0x00, 0x01,
+            0x7f, 0x02, 0x0f, 0x01, 0x06, 0x65, 0x78, 0x74, 0x65, 0x72, 0x6e, 0x04, 0x69, 0x6e,
+            0x63, 0x72, 0x00, 0x00, 0x03, 0x02, 0x01, 0x00, 0x05, 0x03, 0x01, 0x00, 0x01, 0x07,
+            0x10, 0x02, 0x06, 0x6d, 0x65, 0x6d, 0x6f, 0x72, 0x79, 0x02, 0x00, 0x03, 0x72, 0x75,
+            0x6e, 0x00, 0x01, 0x0a, 0x06, 0x01, 0x04, 0x00, 0x10, 0x00, 0x0b,
+        ];
+
+        // register two instances of the module
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+        let result = vm.register_wasm_from_import(import);
+        assert!(result.is_ok());
+        let result = vm.register_wasm_from_buffer("app1", wasm);
+        assert!(result.is_ok());
+        let result = vm.register_wasm_from_buffer("app2", wasm);
+        assert!(result.is_ok());
+        let result = vm.store_mut();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+
+        // no state attached
+        let result = vm.run_registered_function("app1", "run", []);
+        assert!(result.is_err());
+
+        // attach a state to each instance
+        let result = store.named_module("app1");
+        assert!(result.is_ok());
+        let mut instance = result.unwrap();
+        assert!(instance.host_state(|counter: &Counter| counter.0).is_none());
+        assert!(instance.set_host_state(Counter(0)).is_ok());
+        assert!(instance.set_host_state(String::from("request-1")).is_ok());
+        let result = store.named_module("app2");
+        assert!(result.is_ok());
+        let mut instance = result.unwrap();
+        assert!(instance.set_host_state(Counter(100)).is_ok());
+
+        // the host function mutates the state of the calling instance across two calls
+        let result = vm.run_registered_function("app1", "run", []);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 1);
+        let result = vm.run_registered_function("app1", "run", []);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 2);
+        let result = vm.run_registered_function("app2", "run", []);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 101);
+
+        // the states are visible through another handle of the instance
+        let result = store.named_module("app1");
+        assert!(result.is_ok());
+        let mut instance = result.unwrap();
+        assert_eq!(instance.host_state(|counter: &Counter| counter.0), Some(2));
+        assert_eq!(
+            instance.host_state(|s: &String| s.clone()),
+            Some(String::from("request-1"))
+        );
+        assert!(instance.host_state(|v: &u64| *v).is_none());
+
+        // detach the state
+        assert_eq!(instance.remove_host_state::<Counter>(), Some(Counter(2)));
+        assert!(instance.remove_host_state::<Counter>().is_none());
+        let result = vm.run_registered_function("app1", "run", []);
+        assert!(result.is_err());
+
+        // the instance of the ImportObject exports no memory, but can hold a state
+        let result = store.named_module("extern");
+        assert!(result.is_ok());
+        let mut instance = result.unwrap();
+        assert!(instance.set_host_state(Counter(7)).is_ok());
+        assert_eq!(instance.host_state(|counter: &Counter| counter.0), Some(7));
+
+        // the active instance has no name
+        let result = vm.load_wasm_from_buffer(wasm);
+        assert!(result.is_ok());
+        let result = vm.validate();
+        assert!(result.is_ok());
+        let result = vm.instantiate();
+        assert!(result.is_ok());
+        let result = store.active_module();
+        assert!(result.is_ok());
+        let mut instance = result.unwrap();
+        let result = instance.set_host_state(Counter(0));
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Instance(InstanceError::UnnamedHostState)
+        );
+
+        // the states are dropped together with the Vm owning the store
+        let store_addr = store.inner.0 as usize;
+        assert!(HOST_STATES.lock().unwrap().contains_key(&store_addr));
+        drop(store);
+        drop(vm);
+        assert!(!HOST_STATES.lock().unwrap().contains_key(&store_addr));
+    }
+
+    #[test]
+    fn test_instance_call_with_scratch() {
+        // (module
+        //   (memory (export "memory") 1)
+        //   (global $freed (export "freed") (mut i32) (i32.const 0))
+        //   (func (export "malloc") (param i32) (result i32) (i32.const 1024))
+        //   (func (export "free") (param i32) (global.set $freed (local.get 0)))
+        //   (func (export "fill") (param $ptr i32) (param $len i32) (param $val i32) (result i32)
+        //     (local $i i32)
+        //     (block
+        //       (loop
+        //         (br_if 1 (i32.ge_u (local.get $i) (local.get $len)))
+        //         (i32.store8 (i32.add (local.get $ptr) (local.get $i)) (local.get $val))
+        //         (local.set $i (i32.add (local.get $i) (i32.const 1)))
+        //         (br 0)))
+        //     (local.get $len)))
+        let wasm = [
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x11, 0x03, 0x60, 0x01, 0x7f,
+            0x01, 0x7f, 0x60, 0x01, 0x7f, 0x00, 0x60, 0x03, 0x7f, 0x7f, 0x7f, 0x01, 0x7f, 0x03,
+            0x04, 0x03, 0x00, 0x01, 0x02, 0x05, 0x03, 0x01, 0x00, 0x01, 0x06, 0x06, 0x01, 0x7f,
+            0x01, 0x41, 0x00, 0x0b, 0x07, 0x29, 0x05, 0x06, 0x6d, 0x65, 0x6d, 0x6f, 0x72, 0x79,
+            0x02, 0x00, 0x05, 0x66, 0x72, 0x65, 0x65, 0x64, 0x03, 0x00, 0x06, 0x6d, 0x61, 0x6c,
+            0x6c, 0x6f, 0x63, 0x00, 0x00, 0x04, 0x66, 0x72, 0x65, 0x65, 0x00, 0x01, 0x04, 0x66,
+            0x69, 0x6c, 0x6c, 0x00, 0x02, 0x0a, 0x35, 0x03, 0x05, 0x00, 0x41, 0x80, 0x08, 0x0b,
+            0x06, 0x00, 0x20, 0x00, 0x24, 0x00, 0x0b, 0x26, 0x01, 0x01, 0x7f, 0x02, 0x40, 0x03,
+            0x40, 0x20, 0x03, 0x20, 0x01, 0x4f, 0x0d, 0x01, 0x20, 0x00, 0x20, 0x03, 0x6a, 0x20,
+            0x02, 0x3a, 0x00, 0x00, 0x20, 0x03, 0x41, 0x01, 0x6a, 0x21, 0x03, 0x0c, 0x00, 0x0b,
+            0x0b, 0x20, 0x01, 0x0b,
+        ];
+
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+        let result = vm.register_wasm_from_buffer("guest", &wasm);
+        assert!(result.is_ok());
+        let result = vm.store_mut();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+        let result = store.named_module("guest");
+        assert!(result.is_ok());
+        let instance = result.unwrap();
+
+        // fill the scratch buffer
+        let result = instance.call_with_scratch("fill", 16, &[WasmValue::from_i32(7)]);
+        assert!(result.is_ok());
+        let returns = result.unwrap();
+        assert_eq!(returns.len(), 1);
+        assert_eq!(returns[0].to_i32(), 16);
+
+        // check the scratch buffer
+        let result = instance.find_memory("memory");
+        assert!(result.is_ok());
+        let mem = result.unwrap();
+        let result = mem.get_data(1024, 17);
+        assert!(result.is_ok());
+        let mut expected = vec![7; 16];
+        expected.push(0);
+        assert_eq!(result.unwrap(), expected);
+
+        // the scratch buffer is freed
+        let result = instance.find_global("freed");
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().get_value().to_i32(), 1024);
+    }
+
     #[test]
     fn test_instance_get() {
         let module_name = "extern_module";
@@ -535,7 +1322,7 @@ mod tests {
         vm
     }
 
-    fn real_add(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+    fn real_add(_caller: &Caller, inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
         if inputs.len() != 2 {
             return Err(1);
         }
@@ -556,4 +1343,8 @@ mod tests {
 
         Ok(vec![WasmValue::from_i32(c)])
     }
+
+    fn real_abi_version(_caller: &Caller, _inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+        Ok(vec![WasmValue::from_i64(3)])
+    }
 }
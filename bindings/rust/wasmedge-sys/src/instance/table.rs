@@ -6,9 +6,9 @@
 //! the end resticts the upper bound (inclusive).
 
 use crate::{
-    error::{check, TableError, WasmEdgeError},
+    error::{check, CoreError, CoreExecutionError, TableError, WasmEdgeError},
     ffi,
-    types::{RefType, WasmValue},
+    types::{RefType, ValType, WasmValue},
     WasmEdgeResult,
 };
 use std::ops::RangeInclusive;
@@ -54,6 +54,66 @@ impl Table {
         }
     }
 
+    /// Creates a new [Table] sized to the given elements, and then sets the elements into the [Table] in order.
+    ///
+    /// # Arguments
+    ///
+    /// - `elem_ty` specifies the element type of the new [Table].
+    ///
+    /// - `elems` specifies the initial elements of the new [Table]. The type of each element must match `elem_ty`.
+    ///
+    /// - `max` specifies the maximum size of the new [Table]. If `None`, the [Table] has no maximum size.
+    ///
+    /// # Error
+    ///
+    /// If the type of an element does not match `elem_ty`, or `max` is less than the number of the elements, or
+    /// fail to create the [Table], then an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use wasmedge_sys::{RefType, Table, WasmValue};
+    ///
+    /// let elems = vec![WasmValue::from_null_ref(RefType::FuncRef); 3];
+    /// let table = Table::with_elements(RefType::FuncRef, elems, Some(10)).expect("fail to create a Table");
+    /// assert_eq!(table.capacity(), 3);
+    /// ```
+    pub fn with_elements(
+        elem_ty: RefType,
+        elems: Vec<WasmValue>,
+        max: Option<u32>,
+    ) -> WasmEdgeResult<Self> {
+        let expected = elem_val_type(elem_ty);
+        if elems.iter().any(|elem| elem.ty() != expected) {
+            return Err(WasmEdgeError::Table(TableError::UnmatchedRefType));
+        }
+
+        let size = elems.len() as u32;
+        let limit = match max {
+            Some(max) if max < size => {
+                return Err(WasmEdgeError::Table(TableError::ElemsExceedMax))
+            }
+            Some(max) => ffi::WasmEdge_Limit {
+                Min: size,
+                Max: max,
+                HasMax: true,
+            },
+            None => ffi::WasmEdge_Limit {
+                Min: size,
+                Max: size,
+                HasMax: false,
+            },
+        };
+
+        let ty = TableType::from_limit(elem_ty, limit)?;
+        let mut table = Table::create(&ty)?;
+        for (idx, elem) in elems.into_iter().enumerate() {
+            table.set_data(elem, idx as u32)?;
+        }
+
+        Ok(table)
+    }
+
     /// Returns the [TableType] of the [Table].
     ///
     /// # Error
@@ -113,6 +173,51 @@ impl Table {
         }
     }
 
+    /// Sets the given element values at the consecutive positions starting from `start` in the [Table].
+    ///
+    /// # Arguments
+    ///
+    /// - `start` specifies the position of the first value to be stored in the [Table].
+    ///
+    /// - `values` specifies the new values. The type of each value must match the element type of the [Table].
+    ///
+    /// # Error
+    ///
+    /// If `start + values.len()` is larger than the capacity of the [Table], or the type of a value does not match
+    /// the element type of the [Table], then an error is returned and the [Table] is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use wasmedge_sys::{RefType, TableType, Table, WasmValue};
+    ///
+    /// // create a TableType instance and a Table
+    /// let ty = TableType::create(RefType::ExternRef, 10..=20).expect("fail to create a TableType");
+    /// let mut table = Table::create(&ty).expect("fail to create a Table");
+    ///
+    /// // set the values at the positions 5, 6, and 7
+    /// let values = vec![WasmValue::from_null_ref(RefType::ExternRef); 3];
+    /// table.set_range(5, &values).expect("fail to set the values");
+    /// ```
+    pub fn set_range(&mut self, start: u32, values: &[WasmValue]) -> WasmEdgeResult<()> {
+        if start as usize + values.len() > self.capacity() {
+            return Err(WasmEdgeError::Core(CoreError::Execution(
+                CoreExecutionError::TableOutOfBounds,
+            )));
+        }
+
+        let expected = elem_val_type(self.ty()?.elem_ty());
+        if values.iter().any(|value| value.ty() != expected) {
+            return Err(WasmEdgeError::Table(TableError::UnmatchedRefType));
+        }
+
+        for (idx, value) in (start..).zip(values) {
+            self.set_data(*value, idx)?;
+        }
+
+        Ok(())
+    }
+
     /// Returns the capacity of the [Table].
     ///
     /// # Example
@@ -199,12 +304,13 @@ impl TableType {
     /// ```
     ///
     pub fn create(elem_ty: RefType, limit: RangeInclusive<u32>) -> WasmEdgeResult<Self> {
-        let ctx = unsafe {
-            ffi::WasmEdge_TableTypeCreate(
-                ffi::WasmEdge_RefType::from(elem_ty),
-                ffi::WasmEdge_Limit::from(limit),
-            )
-        };
+        Self::from_limit(elem_ty, ffi::WasmEdge_Limit::from(limit))
+    }
+
+    /// Creates a new [TableType] with the given raw limit, which keeps a max size equal to the min size.
+    pub(crate) fn from_limit(elem_ty: RefType, limit: ffi::WasmEdge_Limit) -> WasmEdgeResult<Self> {
+        let ctx =
+            unsafe { ffi::WasmEdge_TableTypeCreate(ffi::WasmEdge_RefType::from(elem_ty), limit) };
         match ctx.is_null() {
             true => Err(WasmEdgeError::TableTypeCreate),
             false => Ok(Self {
@@ -244,10 +350,18 @@ pub(crate) struct InnerTableType(pub(crate) *mut ffi::WasmEdge_TableTypeContext)
 unsafe impl Send for InnerTableType {}
 unsafe impl Sync for InnerTableType {}
 
+/// Returns the value type of the elements of the given reference type.
+fn elem_val_type(elem_ty: RefType) -> ValType {
+    match elem_ty {
+        RefType::FuncRef => ValType::FuncRef,
+        RefType::ExternRef => ValType::ExternRef,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{FuncType, Function, RefType, ValType};
+    use crate::{Caller, FuncType, Function, RefType};
     use std::{
         sync::{Arc, Mutex},
         thread,
@@ -354,6 +468,135 @@ mod tests {
         assert_eq!(return_tys, [ValType::I32]);
     }
 
+    #[test]
+    fn test_table_with_elements() {
+        // create two host functions
+        let result = FuncType::create(vec![ValType::I32; 2], vec![ValType::I32]);
+        assert!(result.is_ok());
+        let func_ty = result.unwrap();
+        let result = Function::create(&func_ty, Box::new(real_add), 0);
+        assert!(result.is_ok());
+        let mut func_1 = result.unwrap();
+        let result = Function::create(&func_ty, Box::new(real_add), 0);
+        assert!(result.is_ok());
+        let mut func_2 = result.unwrap();
+
+        // create a Table instance from the function references
+        let elems = vec![
+            WasmValue::from_func_ref(&mut func_1),
+            WasmValue::from_func_ref(&mut func_2),
+        ];
+        let result = Table::with_elements(RefType::FuncRef, elems, Some(10));
+        assert!(result.is_ok());
+        let table = result.unwrap();
+
+        // check capacity and limit
+        assert_eq!(table.capacity(), 2);
+        let result = table.ty();
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        assert_eq!(ty.limit(), 2..=10);
+        assert_eq!(ty.elem_ty(), RefType::FuncRef);
+
+        // check the elements
+        for idx in 0..2 {
+            let result = table.get_data(idx);
+            assert!(result.is_ok());
+            let value = result.unwrap();
+            assert_eq!(value.ty(), ValType::FuncRef);
+            assert!(value.func_ref().is_some());
+        }
+
+        // the type of an element does not match the element type
+        let elems = vec![
+            WasmValue::from_func_ref(&mut func_1),
+            WasmValue::from_i32(1),
+        ];
+        let result = Table::with_elements(RefType::FuncRef, elems, None);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Table(TableError::UnmatchedRefType)
+        );
+
+        // the number of the elements exceeds the maximum size
+        let elems = vec![WasmValue::from_null_ref(RefType::ExternRef); 3];
+        let result = Table::with_elements(RefType::ExternRef, elems, Some(2));
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Table(TableError::ElemsExceedMax)
+        );
+
+        // the maximum size equals the number of the elements
+        let elems = vec![WasmValue::from_null_ref(RefType::ExternRef); 3];
+        let result = Table::with_elements(RefType::ExternRef, elems, Some(3));
+        assert!(result.is_ok());
+        let mut table = result.unwrap();
+        assert_eq!(table.capacity(), 3);
+
+        // the table cannot grow beyond its maximum size
+        let result = table.grow(1);
+        assert!(result.is_err());
+        assert_eq!(table.capacity(), 3);
+    }
+
+    #[test]
+    fn test_table_set_range() {
+        // create a Table instance with 10 null function references
+        let result = TableType::create(RefType::FuncRef, 10..=20);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Table::create(&ty);
+        assert!(result.is_ok());
+        let mut table = result.unwrap();
+
+        // create a host function
+        let result = FuncType::create(vec![ValType::I32; 2], vec![ValType::I32]);
+        assert!(result.is_ok());
+        let func_ty = result.unwrap();
+        let result = Function::create(&func_ty, Box::new(real_add), 0);
+        assert!(result.is_ok());
+        let mut host_func = result.unwrap();
+
+        // set the function references at the positions 5, 6, and 7
+        let values = vec![WasmValue::from_func_ref(&mut host_func); 3];
+        let result = table.set_range(5, &values);
+        assert!(result.is_ok());
+
+        // check the elements
+        for idx in 0..10 {
+            let result = table.get_data(idx);
+            assert!(result.is_ok());
+            let value = result.unwrap();
+            assert_eq!(value.ty(), ValType::FuncRef);
+            assert_eq!(value.func_ref().is_some(), (5..8).contains(&idx));
+        }
+
+        // the range exceeds the capacity
+        let result = table.set_range(8, &values);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::TableOutOfBounds))
+        );
+        let result = table.get_data(8);
+        assert!(result.is_ok());
+        assert!(result.unwrap().func_ref().is_none());
+
+        // the type of a value does not match the element type
+        let values = vec![
+            WasmValue::from_null_ref(RefType::FuncRef),
+            WasmValue::from_i32(1),
+        ];
+        let result = table.set_range(0, &values);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Table(TableError::UnmatchedRefType)
+        );
+    }
+
     #[test]
     fn test_table_send() {
         // create a TableType instance
@@ -423,7 +666,7 @@ mod tests {
         handle.join().unwrap();
     }
 
-    fn real_add(input: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+    fn real_add(_caller: &Caller, input: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
         println!("Rust: Entering Rust function real_add");
 
         if input.len() != 2 {
@@ -15,6 +15,7 @@
 extern crate lazy_static;
 
 use std::{
+    any::{Any, TypeId},
     collections::HashMap,
     sync::{Arc, Mutex},
 };
@@ -59,21 +60,23 @@ pub use config::Config;
 #[doc(inline)]
 pub use executor::Executor;
 #[doc(inline)]
-pub use import_obj::ImportObject;
+pub use import_obj::{
+    HostCall, HostTrace, ImportObject, RecordingImportObject, ReplayImportObject, TraceValue,
+};
 #[doc(inline)]
 pub use instance::{
-    function::{FuncType, Function},
+    function::{Caller, FuncType, Function},
     global::{Global, GlobalType},
-    memory::{MemType, Memory},
+    memory::{MemType, Memory, MemoryRef, Pod, RegionHandle},
     module::Instance,
     table::{Table, TableType},
 };
 #[doc(inline)]
 pub use loader::Loader;
 #[doc(inline)]
-pub use module::{Export, Import, Module};
+pub use module::{Export, Import, InterfaceMismatch, Module, ResourceLimit, ResourceProfile};
 #[doc(inline)]
-pub use statistics::Statistics;
+pub use statistics::{ExecReport, Statistics};
 #[doc(inline)]
 pub use store::Store;
 #[doc(inline)]
@@ -84,16 +87,20 @@ pub use types::{
 #[doc(inline)]
 pub use validator::Validator;
 #[doc(inline)]
-pub use vm::Vm;
+#[cfg(feature = "async")]
+pub use vm::AsyncVmPool;
+#[doc(inline)]
+pub use vm::{Vm, VmBuilder};
 
 /// The WasmEdge result type.
 pub type WasmEdgeResult<T> = Result<T, error::WasmEdgeError>;
 
 /// Type alias for a host function.
-pub type HostFunc = Box<dyn Fn(Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> + Send + Sync>;
+pub type HostFunc =
+    Box<dyn Fn(&Caller, Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> + Send + Sync>;
 
 lazy_static! {
-    static ref HOST_FUNCS: Arc<Mutex<HashMap<usize, HostFunc>>> =
+    static ref HOST_FUNCS: Arc<Mutex<HashMap<usize, Arc<HostFunc>>>> =
         Arc::new(Mutex::new(HashMap::with_capacity(
             std::env::var("MAX_HOST_FUNC_LENGTH")
                 .map(|s| s
@@ -101,4 +108,18 @@ lazy_static! {
                     .expect("MAX_HOST_FUNC_LENGTH should be a positive integer."))
                 .unwrap_or(500)
         )));
+    // the host states attached to the module instances, keyed by the address of the store holding the module instances
+    static ref HOST_STATES: Mutex<HashMap<usize, StoreHostStates>> = Mutex::new(HashMap::new());
+}
+
+/// Type alias for a host state attached to a module instance.
+type HostState = Arc<Mutex<Box<dyn Any + Send>>>;
+
+/// The host states attached to the module instances in a store.
+#[derive(Default)]
+struct StoreHostStates {
+    // the states keyed by the name of the module instance and the type of the state
+    states: HashMap<(String, TypeId), HostState>,
+    // the names of the module instances keyed by the address of their exported memories
+    mem_owners: HashMap<usize, String>,
 }
@@ -1,12 +1,15 @@
 //! Defines WasmEdge Loader struct.
 
 use crate::{
-    error::{check, WasmEdgeError},
+    error::{check, CoreError, CoreLoadError, WasmEdgeError},
     ffi,
-    module::{InnerModule, Module},
+    module::{self, InnerModule, Module, ResourceProfile},
     utils, Config, WasmEdgeResult,
 };
-use std::path::Path;
+use std::{convert::TryInto, io::Read, path::Path};
+
+// The magic bytes at the beginning of a versioned module file.
+const VERSIONED_MAGIC: &[u8; 8] = b"WECACHE\x01";
 
 /// Struct of WasmEdge Loader.
 ///
@@ -15,6 +18,7 @@ use std::path::Path;
 pub struct Loader {
     pub(crate) inner: InnerLoader,
     pub(crate) registered: bool,
+    max_size: Option<usize>,
 }
 impl Loader {
     /// Create a new [Loader](crate::Loader) to be associated with the given global configuration.
@@ -41,10 +45,44 @@ impl Loader {
             false => Ok(Self {
                 inner: InnerLoader(ctx),
                 registered: false,
+                max_size: None,
             }),
         }
     }
 
+    /// Sets the maximum size in bytes of the WASM modules to be loaded by the [Loader](crate::Loader).
+    ///
+    /// A WASM file or buffer larger than `bytes` is rejected before being parsed, which guards against the
+    /// untrusted modules consuming too many resources.
+    ///
+    /// # Arguments
+    ///
+    /// - `bytes` specifies the maximum size in bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use wasmedge_sys::{error::WasmEdgeError, Loader};
+    ///
+    /// let loader = Loader::create(None).expect("fail to create a Loader").with_max_size(4);
+    /// let result = loader.from_buffer(b"\0asm\x01\0\0\0");
+    /// assert_eq!(result.unwrap_err(), WasmEdgeError::ModuleTooLarge(4));
+    /// ```
+    pub fn with_max_size(self, bytes: usize) -> Self {
+        Self {
+            max_size: Some(bytes),
+            ..self
+        }
+    }
+
+    /// Checks the given size against the maximum size of the modules.
+    fn check_size(&self, size: u64) -> WasmEdgeResult<()> {
+        match self.max_size {
+            Some(max) if size > max as u64 => Err(WasmEdgeError::ModuleTooLarge(max)),
+            _ => Ok(()),
+        }
+    }
+
     /// Loads a WASM module from a WASM file with the suffix `.wasm`.
     ///
     /// # Arguments
@@ -53,7 +91,19 @@ impl Loader {
     ///
     /// # Error
     ///
-    /// If fail to load, then an error is returned.
+    /// If the size of the file exceeds the maximum size set by [Loader::with_max_size], or fail to load, then an error
+    /// is returned.
+    ///
+    /// # Notice
+    ///
+    /// The file is read into memory, and a WASM file is loaded from the bytes read as by [Loader::from_buffer]. If a
+    /// maximum size is set by [Loader::with_max_size], then at most one byte more than the maximum size is read. The
+    /// size reported by the file system is not trusted, since it is zero for the pipes and the procfs files, and the
+    /// file may change after it is checked.
+    ///
+    /// An AOT-compiled file, which is a native shared library or a universal WASM file, is loaded by WasmEdge from its
+    /// path instead, since the native code is only linked in that way. Such a file is opened again after the size
+    /// check, and the loaded [Module] has no [resource profile](crate::Module::resource_profile).
     ///
     /// # Example
     ///
@@ -62,6 +112,20 @@ impl Loader {
     /// let module = loader.from_file(file)?;
     /// ```
     pub fn from_file(&self, file: impl AsRef<Path>) -> WasmEdgeResult<Module> {
+        let limit = self
+            .max_size
+            .map_or(u64::MAX, |max| (max as u64).saturating_add(1));
+        let mut buffer = Vec::new();
+        std::fs::File::open(file.as_ref())
+            .map_err(|_| WasmEdgeError::Core(CoreError::Load(CoreLoadError::IllegalPath)))?
+            .take(limit)
+            .read_to_end(&mut buffer)
+            .map_err(|_| WasmEdgeError::Core(CoreError::Load(CoreLoadError::ReadError)))?;
+        if !is_aot_compiled(&buffer) {
+            return self.from_buffer(buffer);
+        }
+        self.check_size(buffer.len() as u64)?;
+
         let c_path = utils::path_to_cstring(file.as_ref())?;
         let mut mod_ctx = std::ptr::null_mut();
         unsafe {
@@ -76,6 +140,7 @@ impl Loader {
             true => Err(WasmEdgeError::ModuleCreate),
             false => Ok(Module {
                 inner: InnerModule(mod_ctx),
+                profile: None,
             }),
         }
     }
@@ -88,7 +153,8 @@ impl Loader {
     ///
     /// # Error
     ///
-    /// If fail to load, then an error is returned.
+    /// If the size of the buffer exceeds the maximum size set by [Loader::with_max_size], or fail to load, then an
+    /// error is returned.
     ///
     /// # Example
     ///
@@ -103,6 +169,8 @@ impl Loader {
     /// assert!(loader.from_buffer(b"(module)").is_err());
     /// ```
     pub fn from_buffer(&self, buffer: impl AsRef<[u8]>) -> WasmEdgeResult<Module> {
+        self.check_size(buffer.as_ref().len() as u64)?;
+
         let mut mod_ctx: *mut ffi::WasmEdge_ASTModuleContext = std::ptr::null_mut();
 
         unsafe {
@@ -131,9 +199,132 @@ impl Loader {
             true => Err(WasmEdgeError::ModuleCreate),
             false => Ok(Module {
                 inner: InnerModule(mod_ctx),
+                profile: ResourceProfile::parse(buffer.as_ref()),
             }),
         }
     }
+
+    /// Loads a WASM module from a buffer, and saves the buffer to a versioned module file tagged with the version of
+    /// the underlying WasmEdge library and the hash of the buffer.
+    ///
+    /// The versioned module file can be loaded later by [Loader::load_versioned], which rejects the files written by
+    /// the other versions of WasmEdge and the files whose content doesn't match the recorded hash.
+    ///
+    /// # Arguments
+    ///
+    /// - `buffer` specifies a WASM buffer, or an AOT-compiled WASM buffer.
+    ///
+    /// - `path` specifies the path to the versioned module file to write.
+    ///
+    /// # Error
+    ///
+    /// If fail to write the versioned module file, then a
+    /// [VersionedModuleIo](crate::error::WasmEdgeError::VersionedModuleIo) error is returned. If fail to load the
+    /// buffer, then an error is returned.
+    ///
+    /// # Notice
+    ///
+    /// The versioned module file is not a cache of the parsed [Module](crate::Module): the WasmEdge C API doesn't
+    /// support serializing a parsed module, so the file stores the buffer itself, and the buffer is parsed again by
+    /// [Loader::load_versioned]. To skip the compilation work, save an AOT-compiled WASM buffer produced by the
+    /// [Compiler](crate::Compiler).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let module = loader.save_versioned(&wasm_bytes, "path/to/foo.wasmv")?;
+    /// // later, possibly in another process
+    /// let module = loader.load_versioned("path/to/foo.wasmv")?;
+    /// ```
+    pub fn save_versioned(
+        &self,
+        buffer: impl AsRef<[u8]>,
+        path: impl AsRef<Path>,
+    ) -> WasmEdgeResult<Module> {
+        let buffer = buffer.as_ref();
+        let module = self.from_buffer(buffer)?;
+
+        let version = utils::full_version()?;
+        let mut data =
+            Vec::with_capacity(VERSIONED_MAGIC.len() + 4 + version.len() + 16 + buffer.len());
+        data.extend_from_slice(VERSIONED_MAGIC);
+        data.extend_from_slice(&(version.len() as u32).to_le_bytes());
+        data.extend_from_slice(version.as_bytes());
+        data.extend_from_slice(&fnv1a(buffer).to_le_bytes());
+        data.extend_from_slice(&(buffer.len() as u64).to_le_bytes());
+        data.extend_from_slice(buffer);
+        std::fs::write(path.as_ref(), data)
+            .map_err(|e| WasmEdgeError::VersionedModuleIo(e.to_string()))?;
+
+        Ok(module)
+    }
+
+    /// Loads a WASM module from a versioned module file written by [Loader::save_versioned].
+    ///
+    /// # Argument
+    ///
+    /// - `path` specifies the path to the versioned module file.
+    ///
+    /// # Error
+    ///
+    /// If fail to read the versioned module file, then a
+    /// [VersionedModuleIo](crate::error::WasmEdgeError::VersionedModuleIo) error is returned. If the file was written
+    /// by another version of WasmEdge, then a [VersionMismatch](crate::error::WasmEdgeError::VersionMismatch) error is
+    /// returned. If the file is malformed or its content doesn't match the recorded hash, then an
+    /// [InvalidVersionedModule](crate::error::WasmEdgeError::InvalidVersionedModule) error is returned. If fail to
+    /// load the stored buffer, then an error is returned.
+    pub fn load_versioned(&self, path: impl AsRef<Path>) -> WasmEdgeResult<Module> {
+        let data = std::fs::read(path.as_ref())
+            .map_err(|e| WasmEdgeError::VersionedModuleIo(e.to_string()))?;
+        let truncated = || {
+            WasmEdgeError::InvalidVersionedModule(String::from("truncated versioned module file"))
+        };
+
+        // magic
+        if !data.starts_with(VERSIONED_MAGIC) {
+            return Err(WasmEdgeError::InvalidVersionedModule(String::from(
+                "bad magic",
+            )));
+        }
+        let rest = &data[VERSIONED_MAGIC.len()..];
+
+        // version tag
+        if rest.len() < 4 {
+            return Err(truncated());
+        }
+        let (len, rest) = rest.split_at(4);
+        let len = u32::from_le_bytes(len.try_into().unwrap()) as usize;
+        if rest.len() < len {
+            return Err(truncated());
+        }
+        let (found, rest) = rest.split_at(len);
+        let expected = utils::full_version()?;
+        if found != expected.as_bytes() {
+            return Err(WasmEdgeError::VersionMismatch {
+                expected: expected.to_string(),
+                found: String::from_utf8_lossy(found).into_owned(),
+            });
+        }
+
+        // hash and payload
+        if rest.len() < 16 {
+            return Err(truncated());
+        }
+        let (hash, rest) = rest.split_at(8);
+        let (len, payload) = rest.split_at(8);
+        let hash = u64::from_le_bytes(hash.try_into().unwrap());
+        let len = u64::from_le_bytes(len.try_into().unwrap());
+        if payload.len() as u64 != len {
+            return Err(truncated());
+        }
+        if fnv1a(payload) != hash {
+            return Err(WasmEdgeError::InvalidVersionedModule(String::from(
+                "the content doesn't match the hash",
+            )));
+        }
+
+        self.from_buffer(payload)
+    }
 }
 impl Drop for Loader {
     fn drop(&mut self) {
@@ -143,6 +334,24 @@ impl Drop for Loader {
     }
 }
 
+// Checks if the given file content is AOT-compiled, that is, a native shared library, or a universal WASM binary
+// which holds the native code in the custom section named "wasmedge".
+fn is_aot_compiled(buffer: &[u8]) -> bool {
+    match module::sections(buffer) {
+        Some(sections) => sections
+            .iter()
+            .any(|(id, content)| *id == 0 && content.starts_with(b"\x08wasmedge")),
+        None => !buffer.starts_with(module::WASM_HEADER),
+    }
+}
+
+// 64-bit FNV-1a hash, which is stable across the Rust toolchains.
+fn fnv1a(data: &[u8]) -> u64 {
+    data.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
 #[derive(Debug)]
 pub(crate) struct InnerLoader(pub(crate) *mut ffi::WasmEdge_LoaderContext);
 unsafe impl Send for InnerLoader {}
@@ -229,6 +438,147 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_loader_with_max_size() {
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/fibonacci.wasm");
+        let result = std::fs::metadata(&path);
+        assert!(result.is_ok());
+        let size = result.unwrap().len() as usize;
+
+        // the size of the module is just under the limit
+        let result = Loader::create(None);
+        assert!(result.is_ok());
+        let loader = result.unwrap().with_max_size(size);
+        let result = loader.from_file(&path);
+        assert!(result.is_ok());
+        let result = std::fs::read(&path);
+        assert!(result.is_ok());
+        let buffer = result.unwrap();
+        let result = loader.from_buffer(&buffer);
+        assert!(result.is_ok());
+
+        // the size of the module is just over the limit
+        let result = Loader::create(None);
+        assert!(result.is_ok());
+        let loader = result.unwrap().with_max_size(size - 1);
+        let result = loader.from_file(&path);
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), WasmEdgeError::ModuleTooLarge(size - 1));
+        let result = loader.from_buffer(&buffer);
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), WasmEdgeError::ModuleTooLarge(size - 1));
+
+        // a missing file is an error
+        let result = loader.from_file("not_exist.wasm");
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Core(CoreError::Load(CoreLoadError::IllegalPath))
+        );
+
+        // the file system reports a zero size for the procfs files
+        #[cfg(target_os = "linux")]
+        {
+            let result = std::fs::metadata("/proc/self/status");
+            assert!(result.is_ok());
+            assert_eq!(result.unwrap().len(), 0);
+            let result = Loader::create(None);
+            assert!(result.is_ok());
+            let loader = result.unwrap().with_max_size(16);
+            let result = loader.from_file("/proc/self/status");
+            assert!(result.is_err());
+            assert_eq!(result.unwrap_err(), WasmEdgeError::ModuleTooLarge(16));
+        }
+    }
+
+    #[test]
+    fn test_loader_versioned() {
+        let result = Loader::create(None);
+        assert!(result.is_ok());
+        let loader = result.unwrap();
+
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/fibonacci.wasm");
+        let result = std::fs::read(path);
+        assert!(result.is_ok());
+        let wasm = result.unwrap();
+        // a unique path, so that the concurrent test runs don't clobber each other
+        let file = std::env::temp_dir().join(format!(
+            "wasmedge_sys_test_loader_versioned_{}_{}.wasmv",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+
+        // save a versioned module file
+        let result = loader.save_versioned(&wasm, &file);
+        assert!(result.is_ok());
+        let module = result.unwrap();
+        assert_eq!(module.count_of_exports(), 1);
+
+        // load the versioned module file
+        let result = loader.load_versioned(&file);
+        assert!(result.is_ok());
+        let module = result.unwrap();
+        assert_eq!(module.count_of_exports(), 1);
+
+        // tamper the version tag
+        let result = std::fs::read(&file);
+        assert!(result.is_ok());
+        let data = result.unwrap();
+        let mut tampered = data.clone();
+        tampered[12] ^= 0xff;
+        assert!(std::fs::write(&file, &tampered).is_ok());
+        let result = loader.load_versioned(&file);
+        assert!(result.is_err());
+        assert!(matches!(
+            result.unwrap_err(),
+            WasmEdgeError::VersionMismatch { .. }
+        ));
+
+        // tamper the content
+        let mut tampered = data.clone();
+        let last = tampered.len() - 1;
+        tampered[last] ^= 0xff;
+        assert!(std::fs::write(&file, &tampered).is_ok());
+        let result = loader.load_versioned(&file);
+        assert!(result.is_err());
+        assert!(matches!(
+            result.unwrap_err(),
+            WasmEdgeError::InvalidVersionedModule(_)
+        ));
+
+        // not a versioned module file
+        assert!(std::fs::write(&file, &wasm).is_ok());
+        let result = loader.load_versioned(&file);
+        assert!(result.is_err());
+        assert!(matches!(
+            result.unwrap_err(),
+            WasmEdgeError::InvalidVersionedModule(_)
+        ));
+
+        assert!(std::fs::remove_file(&file).is_ok());
+
+        // a missing file is an I/O error
+        let result = loader.load_versioned(&file);
+        assert!(result.is_err());
+        assert!(matches!(
+            result.unwrap_err(),
+            WasmEdgeError::VersionedModuleIo(_)
+        ));
+
+        // fail to write into a missing directory
+        let result = loader.save_versioned(&wasm, file.join("missing"));
+        assert!(result.is_err());
+        assert!(matches!(
+            result.unwrap_err(),
+            WasmEdgeError::VersionedModuleIo(_)
+        ));
+    }
+
     #[test]
     fn test_loader_send() {
         // create a Loader instance without configuration
@@ -9,7 +9,7 @@ use crate::{
         memory::{InnerMemType, MemType},
         table::{InnerTableType, TableType},
     },
-    types::ExternalType,
+    types::{ExternalType, ValType},
     WasmEdgeResult,
 };
 use std::{borrow::Cow, ffi::CStr};
@@ -23,6 +23,8 @@ use std::{borrow::Cow, ffi::CStr};
 #[derive(Debug)]
 pub struct Module {
     pub(crate) inner: InnerModule,
+    // the resource profile aggregated from the WASM binary the module is parsed from, if available
+    pub(crate) profile: Option<ResourceProfile>,
 }
 impl Drop for Module {
     fn drop(&mut self) {
@@ -77,6 +79,268 @@ impl Module {
             })
             .collect()
     }
+
+    /// Returns the [ResourceProfile] of the [Module] without instantiating it, or `None` if the [Module] is loaded
+    /// from an AOT-compiled file.
+    ///
+    /// # Notice
+    ///
+    /// The WasmEdge C API doesn't expose the sections of a [Module], so the profile is aggregated from the import,
+    /// function, table, memory, and global sections of the WASM binary when the [Module] is loaded by
+    /// [Loader::from_buffer](crate::Loader::from_buffer) or [Loader::from_file](crate::Loader::from_file). The native
+    /// code of an AOT-compiled file is only linked when WasmEdge loads it from its path, so the binary of such a file
+    /// is not available to the profile.
+    pub fn resource_profile(&self) -> Option<&ResourceProfile> {
+        self.profile.as_ref()
+    }
+
+    /// Checks if the [Module] exports exactly the given [functions](crate::Function) with the given
+    /// [signatures](crate::FuncType).
+    ///
+    /// # Argument
+    ///
+    /// - `expected` specifies the names and the [types](crate::FuncType) of the expected exported functions.
+    ///
+    /// # Error
+    ///
+    /// If any expected function is not exported or has a different signature, or the [Module] exports a function not
+    /// expected, then all the mismatches are returned.
+    pub fn check_interface(
+        &self,
+        expected: &[(String, FuncType)],
+    ) -> Result<(), Vec<InterfaceMismatch>> {
+        let actual = self
+            .exports()
+            .into_iter()
+            .filter(|export| export.ty() == ExternalType::Function)
+            .map(|export| {
+                let signature = export
+                    .function_type()
+                    .map(|ty| signature(&ty))
+                    .unwrap_or_default();
+                (export.name().into_owned(), signature)
+            })
+            .collect::<Vec<_>>();
+
+        let mut mismatches = Vec::new();
+        for (name, ty) in expected {
+            let expected = signature(ty);
+            match actual.iter().find(|(actual_name, _)| actual_name == name) {
+                None => mismatches.push(InterfaceMismatch::Missing(name.clone())),
+                Some((_, actual)) if *actual != expected => {
+                    mismatches.push(InterfaceMismatch::Signature {
+                        name: name.clone(),
+                        expected,
+                        actual: actual.clone(),
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+        for (name, _) in actual.iter() {
+            if !expected
+                .iter()
+                .any(|(expected_name, _)| expected_name == name)
+            {
+                mismatches.push(InterfaceMismatch::Extra(name.clone()));
+            }
+        }
+
+        match mismatches.is_empty() {
+            true => Ok(()),
+            false => Err(mismatches),
+        }
+    }
+}
+
+fn signature(ty: &FuncType) -> (Vec<ValType>, Vec<ValType>) {
+    (
+        ty.params_type_iter().collect(),
+        ty.returns_type_iter().collect(),
+    )
+}
+
+/// Defines the mismatches between the exported [functions](crate::Function) of a [Module] and the expected interface.
+///
+/// The mismatches are returned by [Module::check_interface]. A signature is represented as the types of the
+/// parameters and the types of the returns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InterfaceMismatch {
+    /// The expected function is not exported.
+    Missing(String),
+    /// The exported function is not expected.
+    Extra(String),
+    /// The exported function has a different signature from the expected one.
+    Signature {
+        name: String,
+        expected: (Vec<ValType>, Vec<ValType>),
+        actual: (Vec<ValType>, Vec<ValType>),
+    },
+}
+
+/// Struct of the resource requirements declared by a [Module].
+///
+/// A [ResourceProfile] is returned by [Module::resource_profile], and can be used to reject the modules exceeding the
+/// quotas before instantiating them. The imported items come first, followed by the items defined by the [Module].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResourceProfile {
+    /// The limits, in pages, of the imported and defined memories.
+    pub memories: Vec<ResourceLimit>,
+    /// The limits, in elements, of the imported and defined tables.
+    pub tables: Vec<ResourceLimit>,
+    /// The number of the imported and defined functions.
+    pub funcs: u32,
+    /// The number of the imported and defined globals.
+    pub globals: u32,
+}
+impl ResourceProfile {
+    /// Aggregates the profile from the sections of the given WASM binary, or returns `None` if the binary is
+    /// malformed.
+    pub(crate) fn parse(wasm: &[u8]) -> Option<Self> {
+        let mut profile = Self::default();
+        for (id, content) in sections(wasm)? {
+            let mut reader = Reader(content);
+            match id {
+                // import section
+                2 => {
+                    for _ in 0..reader.u32()? {
+                        // the module name and the field name
+                        reader.name()?;
+                        reader.name()?;
+                        match reader.byte()? {
+                            0x00 => {
+                                reader.u32()?;
+                                profile.funcs += 1;
+                            }
+                            0x01 => {
+                                reader.byte()?;
+                                profile.tables.push(reader.limit()?);
+                            }
+                            0x02 => profile.memories.push(reader.limit()?),
+                            0x03 => {
+                                reader.byte()?;
+                                reader.byte()?;
+                                profile.globals += 1;
+                            }
+                            _ => return None,
+                        }
+                    }
+                }
+                // function section
+                3 => profile.funcs += reader.u32()?,
+                // table section
+                4 => {
+                    for _ in 0..reader.u32()? {
+                        reader.byte()?;
+                        profile.tables.push(reader.limit()?);
+                    }
+                }
+                // memory section
+                5 => {
+                    for _ in 0..reader.u32()? {
+                        profile.memories.push(reader.limit()?);
+                    }
+                }
+                // global section
+                6 => profile.globals += reader.u32()?,
+                _ => {}
+            }
+        }
+        Some(profile)
+    }
+
+    /// Returns the total initial size in pages of the memories.
+    pub fn initial_memory_pages(&self) -> u64 {
+        self.memories.iter().map(|limit| limit.min as u64).sum()
+    }
+
+    /// Returns the total max size in pages of the memories, or `None` if any memory has no max size.
+    pub fn max_memory_pages(&self) -> Option<u64> {
+        self.memories
+            .iter()
+            .map(|limit| limit.max.map(u64::from))
+            .sum()
+    }
+}
+
+/// Struct of the limit of a memory or a table declared by a [Module].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResourceLimit {
+    /// The initial size.
+    pub min: u32,
+    /// The max size, or `None` if the size is unbounded.
+    pub max: Option<u32>,
+}
+
+/// Splits the given WASM binary into its sections of the ids and the contents, or returns `None` if the binary is
+/// malformed.
+pub(crate) fn sections(wasm: &[u8]) -> Option<Vec<(u8, &[u8])>> {
+    if !wasm.starts_with(WASM_HEADER) {
+        return None;
+    }
+    let mut reader = Reader(&wasm[WASM_HEADER.len()..]);
+    let mut sections = Vec::new();
+    while !reader.0.is_empty() {
+        let id = reader.byte()?;
+        let len = reader.u32()?;
+        sections.push((id, reader.bytes(len)?));
+    }
+    Some(sections)
+}
+
+// The magic bytes and the version at the beginning of a WASM binary.
+pub(crate) const WASM_HEADER: &[u8; 8] = b"\0asm\x01\0\0\0";
+
+// A cursor over the bytes of a WASM binary.
+struct Reader<'a>(&'a [u8]);
+impl<'a> Reader<'a> {
+    fn byte(&mut self) -> Option<u8> {
+        let (&byte, rest) = self.0.split_first()?;
+        self.0 = rest;
+        Some(byte)
+    }
+
+    fn bytes(&mut self, len: u32) -> Option<&'a [u8]> {
+        let len = len as usize;
+        if self.0.len() < len {
+            return None;
+        }
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Some(bytes)
+    }
+
+    // an unsigned LEB128 integer of at most 32 bits
+    fn u32(&mut self) -> Option<u32> {
+        let mut value = 0u32;
+        for shift in (0..35).step_by(7) {
+            let byte = self.byte()?;
+            // the last byte holds the 4 highest bits only
+            if shift == 28 && byte & 0x70 != 0 {
+                return None;
+            }
+            value |= ((byte & 0x7f) as u32) << shift;
+            if byte & 0x80 == 0 {
+                return Some(value);
+            }
+        }
+        None
+    }
+
+    fn name(&mut self) -> Option<&'a [u8]> {
+        let len = self.u32()?;
+        self.bytes(len)
+    }
+
+    fn limit(&mut self) -> Option<ResourceLimit> {
+        let flags = self.byte()?;
+        let min = self.u32()?;
+        let max = match flags & 0x01 {
+            0 => None,
+            _ => Some(self.u32()?),
+        };
+        Some(ResourceLimit { min, max })
+    }
 }
 
 #[derive(Debug)]
@@ -202,6 +466,17 @@ impl<'module> Import<'module> {
         }
     }
 
+    /// Returns a new [memory type](crate::MemType) with the same limit as the imported [memory](crate::Memory), or
+    /// `None` if the [Import] is not a memory import.
+    ///
+    /// Different from [Import::memory_type], the returned [MemType](crate::MemType) is owned by the caller and
+    /// outlives the [Module], so it can be used to create a [Memory](crate::Memory) satisfying the import.
+    pub fn as_mem_type(&self) -> Option<MemType> {
+        let mem_ty = self.memory_type().ok()?;
+        let limit = unsafe { ffi::WasmEdge_MemoryTypeGetLimit(mem_ty.inner.0) };
+        MemType::from_limit(limit).ok()
+    }
+
     /// Returns the [global type](crate::GlobalType).
     ///
     /// If fail to get the global type, then an error is returned.
@@ -370,13 +645,158 @@ unsafe impl Sync for InnerExport {}
 mod tests {
     use crate::{
         error::{ExportError, ImportError, WasmEdgeError},
-        Config, ExternalType, Loader, Mutability, RefType, ValType,
+        Config, ExternalType, FuncType, InterfaceMismatch, Loader, Memory, Mutability, RefType,
+        ResourceLimit, ValType,
     };
     use std::{
         sync::{Arc, Mutex},
         thread,
     };
 
+    #[test]
+    fn test_module_resource_profile() {
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/import.wasm");
+
+        let result = Config::create();
+        assert!(result.is_ok());
+        let mut config = result.unwrap();
+        config.bulk_memory_operations(true);
+        assert!(config.bulk_memory_operations_enabled());
+
+        // load module from file
+        let result = Loader::create(Some(config));
+        assert!(result.is_ok());
+        let loader = result.unwrap();
+        let result = loader.from_file(path);
+        assert!(result.is_ok());
+        let module = result.unwrap();
+
+        // check the resource profile: the imported items come first
+        let result = module.resource_profile();
+        assert!(result.is_some());
+        let profile = result.unwrap();
+        let limit = |min, max| ResourceLimit { min, max };
+        assert_eq!(
+            profile.memories,
+            [limit(1, Some(3)), limit(2, None), limit(1, Some(3))]
+        );
+        assert_eq!(profile.initial_memory_pages(), 4);
+        // the imported "mem2" has no max size
+        assert!(profile.max_memory_pages().is_none());
+        assert_eq!(
+            profile.tables,
+            [
+                limit(10, Some(20)),
+                limit(10, Some(30)),
+                limit(10, None),
+                limit(10, None)
+            ]
+        );
+        // 6 imported and 11 defined functions
+        assert_eq!(profile.funcs, 17);
+        // 4 imported and 2 defined globals
+        assert_eq!(profile.globals, 6);
+
+        // (module
+        //   (memory (export "memory") 1 2))
+        let wasm: &[u8] = &[
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x05, 0x04, 0x01, 0x01, 0x01, 0x02,
+            0x07, 0x0a, 0x01, 0x06, 0x6d, 0x65, 0x6d, 0x6f, 0x72, 0x79, 0x02, 0x00,
+        ];
+        let result = Loader::create(None);
+        assert!(result.is_ok());
+        let loader = result.unwrap();
+        let result = loader.from_buffer(wasm);
+        assert!(result.is_ok());
+        let module = result.unwrap();
+        let result = module.resource_profile();
+        assert!(result.is_some());
+        let profile = result.unwrap();
+        assert_eq!(profile.memories, [limit(1, Some(2))]);
+        assert_eq!(profile.initial_memory_pages(), 1);
+        assert_eq!(profile.max_memory_pages(), Some(2));
+
+        // (module
+        //   (import "env" "mem" (memory 1 2))
+        //   (export "mem" (memory 0))
+        //   (table 3 funcref)
+        //   (func)
+        //   (global i32 (i32.const 0)))
+        let wasm: &[u8] = &[
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x04, 0x01, 0x60, 0x00, 0x00,
+            0x02, 0x0d, 0x01, 0x03, 0x65, 0x6e, 0x76, 0x03, 0x6d, 0x65, 0x6d, 0x02, 0x01, 0x01,
+            0x02, 0x03, 0x02, 0x01, 0x00, 0x04, 0x04, 0x01, 0x70, 0x00, 0x03, 0x06, 0x06, 0x01,
+            0x7f, 0x00, 0x41, 0x00, 0x0b, 0x07, 0x07, 0x01, 0x03, 0x6d, 0x65, 0x6d, 0x02, 0x00,
+            0x0a, 0x04, 0x01, 0x02, 0x00, 0x0b,
+        ];
+        let result = loader.from_buffer(wasm);
+        assert!(result.is_ok());
+        let module = result.unwrap();
+        let result = module.resource_profile();
+        assert!(result.is_some());
+        let profile = result.unwrap();
+        // the re-exported memory is counted once
+        assert_eq!(profile.memories, [limit(1, Some(2))]);
+        // the items not exported are counted
+        assert_eq!(profile.tables, [limit(3, None)]);
+        assert_eq!(profile.funcs, 1);
+        assert_eq!(profile.globals, 1);
+    }
+
+    #[test]
+    fn test_module_check_interface() {
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/fibonacci.wasm");
+
+        // load module from file
+        let result = Loader::create(None);
+        assert!(result.is_ok());
+        let loader = result.unwrap();
+        let result = loader.from_file(path);
+        assert!(result.is_ok());
+        let module = result.unwrap();
+
+        // the expected interface
+        let result = FuncType::create([ValType::I32], [ValType::I32]);
+        assert!(result.is_ok());
+        let fib_ty = result.unwrap();
+        let result = module.check_interface(&[(String::from("fib"), fib_ty)]);
+        assert!(result.is_ok());
+
+        // an expected export is missing, and another one has a different signature
+        let result = FuncType::create([ValType::I64], [ValType::I64]);
+        assert!(result.is_ok());
+        let fib_ty = result.unwrap();
+        let result = FuncType::create([ValType::I32], [ValType::I32]);
+        assert!(result.is_ok());
+        let fact_ty = result.unwrap();
+        let result = module.check_interface(&[
+            (String::from("fib"), fib_ty),
+            (String::from("fact"), fact_ty),
+        ]);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            vec![
+                InterfaceMismatch::Signature {
+                    name: String::from("fib"),
+                    expected: (vec![ValType::I64], vec![ValType::I64]),
+                    actual: (vec![ValType::I32], vec![ValType::I32]),
+                },
+                InterfaceMismatch::Missing(String::from("fact")),
+            ]
+        );
+
+        // an export is not expected
+        let result = module.check_interface(&[]);
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            vec![InterfaceMismatch::Extra(String::from("fib"))]
+        );
+    }
+
     #[test]
     fn test_module_import() {
         let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
@@ -522,6 +942,84 @@ mod tests {
         assert_eq!(global_ty.mutability(), Mutability::Const);
     }
 
+    #[test]
+    fn test_module_import_as_mem_type() {
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/import.wasm");
+
+        // load module from file
+        let result = Loader::create(None);
+        assert!(result.is_ok());
+        let loader = result.unwrap();
+        let result = loader.from_file(path);
+        assert!(result.is_ok());
+        let module = result.unwrap();
+        let imports = module.imports();
+
+        // non-memory import
+        assert_eq!(imports[0].ty(), ExternalType::Function);
+        assert!(imports[0].as_mem_type().is_none());
+
+        // derive the memory type from the "mem2" memory import
+        assert_eq!(imports[13].name(), "mem2");
+        let result = imports[13].as_mem_type();
+        assert!(result.is_some());
+        let mem_ty = result.unwrap();
+        assert_eq!(mem_ty.limit(), 2..=2);
+
+        // the memory type outlives the module
+        drop(imports);
+        drop(module);
+
+        // create a memory satisfying the import
+        let result = Memory::create(&mem_ty);
+        assert!(result.is_ok());
+        let mem = result.unwrap();
+        assert_eq!(mem.size(), 2);
+        let result = mem.ty();
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().limit(), 2..=2);
+
+        // the memory grows as the "mem2" memory import has no max size
+        let mut mem = mem;
+        let result = mem.grow(1);
+        assert!(result.is_ok());
+        assert_eq!(mem.size(), 3);
+
+        // (module
+        //   (import "env" "mem" (memory 1 1))
+        // )
+        let wasm = vec![
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x02, 0x0d, 0x01, 0x03, 0x65, 0x6e,
+            0x76, 0x03, 0x6d, 0x65, 0x6d, 0x02, 0x01, 0x01, 0x01,
+        ];
+        let result = Loader::create(None);
+        assert!(result.is_ok());
+        let loader = result.unwrap();
+        let result = loader.from_buffer(wasm);
+        assert!(result.is_ok());
+        let module = result.unwrap();
+        let imports = module.imports();
+        assert_eq!(imports.len(), 1);
+
+        // the max size equal to the min size is kept
+        let result = imports[0].as_mem_type();
+        assert!(result.is_some());
+        let mem_ty = result.unwrap();
+        assert_eq!(mem_ty.limit(), 1..=1);
+        let limit = unsafe { ffi::WasmEdge_MemoryTypeGetLimit(mem_ty.inner.0) };
+        assert!(limit.HasMax);
+
+        // the memory created from the memory type cannot grow
+        let result = Memory::create(&mem_ty);
+        assert!(result.is_ok());
+        let mut mem = result.unwrap();
+        assert_eq!(mem.size(), 1);
+        let result = mem.grow(1);
+        assert!(result.is_err());
+        assert_eq!(mem.size(), 1);
+    }
+
     #[test]
     fn test_module_export() {
         let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
@@ -1,12 +1,17 @@
 //! Defines WasmEdge Statistics struct.
 
 use crate::{error::WasmEdgeError, ffi, WasmEdgeResult};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 #[derive(Debug)]
 /// Struct of WasmEdge Statistics.
 pub struct Statistics {
     pub(crate) inner: InnerStat,
     pub(crate) registered: bool,
+    pub(crate) records: Arc<Mutex<Records>>,
 }
 impl Statistics {
     /// Creates a new [Statistics].
@@ -21,6 +26,7 @@ impl Statistics {
             false => Ok(Statistics {
                 inner: InnerStat(ctx),
                 registered: false,
+                records: Arc::new(Mutex::new(Records::default())),
             }),
         }
     }
@@ -83,6 +89,91 @@ impl Statistics {
     pub fn set_cost_limit(&mut self, limit: u64) {
         unsafe { ffi::WasmEdge_StatisticsSetCostLimit(self.inner.0, limit) }
     }
+
+    /// Returns the total time spent in instantiating modules by the [Executor](crate::Executor) or the
+    /// [Vm](crate::Vm) which the [Statistics] is associated with.
+    ///
+    /// The time spent in registering modules and [import objects](crate::ImportObject) is included. For the modules
+    /// registered into a [Vm](crate::Vm) from a file or a buffer, the time also includes loading and validating them,
+    /// since the [Vm](crate::Vm) does these steps in a single call.
+    pub fn instantiate_time(&self) -> Duration {
+        self.records
+            .lock()
+            .expect("[wasmedge-sys] try lock failed.")
+            .instantiate
+    }
+
+    /// Returns the total time spent in invoking functions by the [Executor](crate::Executor) or the
+    /// [Vm](crate::Vm) which the [Statistics] is associated with.
+    pub fn invoke_time(&self) -> Duration {
+        self.records
+            .lock()
+            .expect("[wasmedge-sys] try lock failed.")
+            .invoke
+    }
+
+    /// Enables or disables tracking the peak size of the exported [memories](crate::Memory) of the invoked modules.
+    /// The tracking is disabled by default.
+    ///
+    /// # Argument
+    ///
+    /// - `enable` specifies if the tracking is enabled.
+    ///
+    /// # Notice
+    ///
+    /// WasmEdge provides no hook on the growth of a memory, so, while the tracking is enabled, the size of the
+    /// memories is sampled after each invocation by the [Executor](crate::Executor) or the [Vm](crate::Vm) which the
+    /// [Statistics] is associated with. Since a WASM memory never shrinks, the size after an invocation is also the
+    /// peak size during it. The memories which are not exported are not tracked.
+    pub fn track_memory(&mut self, enable: bool) {
+        self.records
+            .lock()
+            .expect("[wasmedge-sys] try lock failed.")
+            .track_memory = enable;
+    }
+
+    /// Checks if tracking the peak size of the memories is enabled. See [Statistics::track_memory].
+    pub fn is_tracking_memory(&self) -> bool {
+        self.records
+            .lock()
+            .expect("[wasmedge-sys] try lock failed.")
+            .track_memory
+    }
+
+    /// Returns the peak size in pages of the exported [memories](crate::Memory) of the invoked modules, or `None` if
+    /// no function which has access to an exported memory has been invoked since the tracking was enabled by
+    /// [Statistics::track_memory].
+    pub fn peak_memory_pages(&self) -> Option<u32> {
+        self.records
+            .lock()
+            .expect("[wasmedge-sys] try lock failed.")
+            .peak_memory_pages
+    }
+
+    /// Returns an [ExecReport] which bundles the execution statistics collected so far.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use wasmedge_sys::Statistics;
+    ///
+    /// // create a Statistics instance
+    /// let stat = Statistics::create().expect("fail to create a Statistics");
+    ///
+    /// // nothing has been executed yet
+    /// let report = stat.report();
+    /// assert_eq!(report.instr_count, 0);
+    /// assert_eq!(report.cost_in_total, 0);
+    /// assert!(report.instr_per_sec.is_nan());
+    /// ```
+    pub fn report(&self) -> ExecReport {
+        ExecReport {
+            instr_count: self.instr_count(),
+            instr_per_sec: self.instr_per_sec(),
+            cost_in_total: self.cost_in_total(),
+            peak_memory_pages: self.peak_memory_pages(),
+        }
+    }
 }
 impl Drop for Statistics {
     fn drop(&mut self) {
@@ -92,6 +183,41 @@ impl Drop for Statistics {
     }
 }
 
+/// Struct of the execution report generated by a [Statistics].
+///
+/// With the `serde` feature enabled, an [ExecReport] can be serialized, for example, to emit the metrics of a run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExecReport {
+    /// The instruction count in execution.
+    pub instr_count: u64,
+    /// The instruction count per second in execution. It could be `NaN` if the total execution time is 0.
+    pub instr_per_sec: f64,
+    /// The total cost in execution.
+    pub cost_in_total: u64,
+    /// The peak size in pages of the exported memories of the invoked modules, if tracked.
+    pub peak_memory_pages: Option<u32>,
+}
+
+/// The records measured on the host side for the instantiation and invocation phases.
+#[derive(Debug, Default)]
+pub(crate) struct Records {
+    pub(crate) instantiate: Duration,
+    pub(crate) invoke: Duration,
+    pub(crate) peak_memory_pages: Option<u32>,
+    // whether the size of the memories is sampled after each invocation
+    pub(crate) track_memory: bool,
+}
+impl Records {
+    /// Updates the peak memory size with the given page count, if any.
+    pub(crate) fn record_memory_pages(&mut self, pages: Option<u32>) {
+        if let Some(pages) = pages {
+            self.peak_memory_pages =
+                Some(self.peak_memory_pages.map_or(pages, |peak| peak.max(pages)));
+        }
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct InnerStat(pub(crate) *mut ffi::WasmEdge_StatisticsContext);
 unsafe impl Send for InnerStat {}
@@ -100,11 +226,149 @@ unsafe impl Sync for InnerStat {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{Config, Vm, WasmValue};
     use std::{
         sync::{Arc, Mutex},
         thread,
     };
 
+    #[test]
+    fn test_stat_report() {
+        // create a Config context
+        let result = Config::create();
+        assert!(result.is_ok());
+        let mut config = result.unwrap();
+        config.count_instructions(true);
+        config.measure_time(true);
+        config.measure_cost(true);
+
+        // create a Vm context
+        let result = Vm::create(Some(config), None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        // track the memories
+        let result = vm.statistics_mut();
+        assert!(result.is_ok());
+        let mut stat = result.unwrap();
+        stat.track_memory(true);
+
+        // (module
+        //   (memory (export "memory") 2)
+        //   (func (export "size") (result i32)
+        //     memory.size))
+        let wasm: &[u8] = &[
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x05, 0x01, 0x60, 0x00, 0x01,
+            0x7f, 0x03, 0x02, 0x01, 0x00, 0x05, 0x03, 0x01, 0x00, 0x02, 0x07, 0x11, 0x02, 0x06,
+            0x6d, 0x65, 0x6d, 0x6f, 0x72, 0x79, 0x02, 0x00, 0x04, 0x73, 0x69, 0x7a, 0x65, 0x00,
+            0x00, 0x0a, 0x06, 0x01, 0x04, 0x00, 0x3f, 0x00, 0x0b,
+        ];
+        let result = vm.run_wasm_from_buffer(wasm, "size", []);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 2);
+
+        // get the report
+        let report = stat.report();
+        assert!(report.instr_count > 0);
+        assert!(report.cost_in_total > 0);
+        assert_eq!(report.instr_count, stat.instr_count());
+        assert_eq!(report.cost_in_total, stat.cost_in_total());
+        assert_eq!(report.peak_memory_pages, Some(2));
+    }
+
+    #[test]
+    fn test_stat_timings() {
+        // create a Vm context
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        // nothing has been measured yet
+        let result = vm.statistics_mut();
+        assert!(result.is_ok());
+        let stat = result.unwrap();
+        assert_eq!(stat.instantiate_time(), Duration::ZERO);
+        assert_eq!(stat.invoke_time(), Duration::ZERO);
+
+        // run a function
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/fibonacci.wasm");
+        let result = vm.run_wasm_from_file(path, "fib", [WasmValue::from_i32(5)]);
+        assert!(result.is_ok());
+
+        // check the timings
+        let result = vm.statistics_mut();
+        assert!(result.is_ok());
+        let stat = result.unwrap();
+        assert!(stat.instantiate_time() > Duration::ZERO);
+        assert!(stat.invoke_time() > Duration::ZERO);
+
+        // registering a module is measured as instantiation
+        let instantiate_time = stat.instantiate_time();
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/fibonacci.wasm");
+        let result = vm.register_wasm_from_file("extern", path);
+        assert!(result.is_ok());
+        assert!(stat.instantiate_time() > instantiate_time);
+        let invoke_time = stat.invoke_time();
+        let result = vm.run_registered_function("extern", "fib", [WasmValue::from_i32(5)]);
+        assert!(result.is_ok());
+        assert!(stat.invoke_time() > invoke_time);
+    }
+
+    #[test]
+    fn test_stat_peak_memory_pages() {
+        // create a Vm context
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        // (module
+        //   (memory (export "memory") 1)
+        //   (func (export "grow") (param i32) (result i32)
+        //     local.get 0
+        //     memory.grow))
+        let wasm: &[u8] = &[
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x06, 0x01, 0x60, 0x01, 0x7f,
+            0x01, 0x7f, 0x03, 0x02, 0x01, 0x00, 0x05, 0x03, 0x01, 0x00, 0x01, 0x07, 0x11, 0x02,
+            0x06, 0x6d, 0x65, 0x6d, 0x6f, 0x72, 0x79, 0x02, 0x00, 0x04, 0x67, 0x72, 0x6f, 0x77,
+            0x00, 0x00, 0x0a, 0x08, 0x01, 0x06, 0x00, 0x20, 0x00, 0x40, 0x00, 0x0b,
+        ];
+        let result = vm.load_wasm_from_buffer(wasm);
+        assert!(result.is_ok());
+        let result = vm.validate();
+        assert!(result.is_ok());
+        let result = vm.instantiate();
+        assert!(result.is_ok());
+
+        // the memories are not tracked by default
+        let result = vm.statistics_mut();
+        assert!(result.is_ok());
+        let mut stat = result.unwrap();
+        assert!(!stat.is_tracking_memory());
+        let result = vm.run_function("grow", [WasmValue::from_i32(0)]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 1);
+        assert!(stat.peak_memory_pages().is_none());
+
+        // enable the tracking
+        stat.track_memory(true);
+        assert!(stat.is_tracking_memory());
+
+        // grow the memory by 2 pages
+        let result = vm.run_function("grow", [WasmValue::from_i32(2)]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 1);
+        assert_eq!(stat.peak_memory_pages(), Some(3));
+
+        // an invocation without growing keeps the peak
+        let result = vm.run_function("grow", [WasmValue::from_i32(0)]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 3);
+        assert_eq!(stat.peak_memory_pages(), Some(3));
+        assert_eq!(stat.report().peak_memory_pages, Some(3));
+    }
+
     #[test]
     fn test_stat_send() {
         let result = Statistics::create();
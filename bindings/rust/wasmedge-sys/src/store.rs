@@ -7,12 +7,13 @@ use crate::{
         function::{Function, InnerFunc},
         global::{Global, InnerGlobal},
         memory::{InnerMemory, Memory},
-        module::{InnerInstance, Instance},
+        module::{drop_host_states, InnerInstance, Instance},
         table::{InnerTable, Table},
     },
     types::WasmEdgeString,
-    WasmEdgeResult,
+    Executor, Loader, Validator, WasmEdgeResult,
 };
+use std::path::Path;
 
 /// Struct of Wasmedge Store.
 ///
@@ -754,10 +755,87 @@ impl Store {
         }
         Ok(())
     }
+
+    /// Returns the largest size in pages of the exported [memories](crate::Memory) in the registered module specified
+    /// by `mod_name`, or in the anonymous module if `mod_name` is `None`.
+    pub(crate) fn max_mem_pages(&self, mod_name: Option<&str>) -> Option<u32> {
+        match mod_name {
+            Some(mod_name) => self
+                .reg_mem_names(mod_name)?
+                .iter()
+                .filter_map(|name| self.find_memory_registered(mod_name, name).ok())
+                .map(|mem| mem.size())
+                .max(),
+            None => self
+                .mem_names()?
+                .iter()
+                .filter_map(|name| self.find_memory(name).ok())
+                .map(|mem| mem.size())
+                .max(),
+        }
+    }
+
+    /// Loads, validates and registers all the WASM files with the suffix `.wasm` in the given directory into the [Store].
+    ///
+    /// Each module is registered with the name of its file without the suffix, for example, `foo.wasm` is registered as `foo`. The files are registered in the alphabetical order of their names.
+    ///
+    /// # Arguments
+    ///
+    /// - `executor` specifies the [Executor] used to register the modules.
+    ///
+    /// - `dir` specifies the path to the target directory.
+    ///
+    /// # Error
+    ///
+    /// If fail to read the directory, then an error is returned.
+    ///
+    /// A file failing to be loaded, validated or registered does not stop the registration of the rest files. In such case, a [StoreError::RegisterDir] error is returned, which holds the names of the registered modules and the error of each failed file.
+    pub fn register_dir(
+        &mut self,
+        executor: &mut Executor,
+        dir: impl AsRef<Path>,
+    ) -> WasmEdgeResult<Vec<String>> {
+        let entries = std::fs::read_dir(dir.as_ref())
+            .map_err(|e| WasmEdgeError::Store(StoreError::ReadDir(e.to_string())))?;
+        let mut paths = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_file() && path.extension().map_or(false, |ext| ext == "wasm"))
+            .collect::<Vec<_>>();
+        paths.sort();
+
+        let loader = Loader::create(None)?;
+        let validator = Validator::create(None)?;
+
+        let mut registered = Vec::with_capacity(paths.len());
+        let mut failed = Vec::new();
+        for path in paths {
+            let mod_name = match path.file_stem() {
+                Some(stem) => stem.to_string_lossy().into_owned(),
+                None => continue,
+            };
+            let result = loader.from_file(&path).and_then(|module| {
+                validator.validate(&module)?;
+                executor.register_named_module(self, &module, &mod_name)
+            });
+            match result {
+                Ok(_) => registered.push(mod_name),
+                Err(e) => failed.push((path, e)),
+            }
+        }
+
+        match failed.is_empty() {
+            true => Ok(registered),
+            false => Err(WasmEdgeError::Store(StoreError::RegisterDir {
+                registered,
+                failed,
+            })),
+        }
+    }
 }
 impl Drop for Store {
     fn drop(&mut self) {
         if !self.registered && !self.inner.0.is_null() {
+            drop_host_states(self);
             unsafe { ffi::WasmEdge_StoreDelete(self.inner.0) }
         }
     }
@@ -772,9 +850,10 @@ unsafe impl Sync for InnerStore {}
 mod tests {
     use super::Store;
     use crate::{
+        error::{StoreError, WasmEdgeError},
         instance::{Function, Global, GlobalType, MemType, Memory, Table, TableType},
         types::WasmValue,
-        Config, Executor, FuncType, ImportObject, Mutability, RefType, ValType, Vm,
+        Caller, Config, Executor, FuncType, ImportObject, Mutability, RefType, ValType, Vm,
     };
     use std::{
         sync::{Arc, Mutex},
@@ -1107,7 +1186,68 @@ mod tests {
         assert_eq!(return_types, [ValType::I32]);
     }
 
-    fn real_add(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+    #[test]
+    fn test_store_register_dir() {
+        // prepare a directory with two wasm files and an invalid one
+        // a unique path, so that the concurrent test runs don't clobber each other
+        let dir = std::env::temp_dir().join(format!(
+            "wasmedge_sys_test_store_register_dir_{}_{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        assert!(std::fs::create_dir(&dir).is_ok());
+        let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+            .join("bindings/rust/wasmedge-sys/tests/data/fibonacci.wasm");
+        assert!(std::fs::copy(&path, dir.join("fib1.wasm")).is_ok());
+        assert!(std::fs::copy(&path, dir.join("fib2.wasm")).is_ok());
+
+        // create an Executor context
+        let result = Executor::create(None, None);
+        assert!(result.is_ok());
+        let mut executor = result.unwrap();
+
+        // create a Store context
+        let result = Store::create();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+
+        // register the modules in the directory
+        let result = store.register_dir(&mut executor, &dir);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), ["fib1", "fib2"]);
+        assert_eq!(store.reg_module_len(), 2);
+        assert!(store.contains_mod_name("fib1").is_ok());
+        assert!(store.contains_mod_name("fib2").is_ok());
+        assert!(store.find_func_registered("fib2", "fib").is_ok());
+
+        // add an invalid wasm file, and register the directory into a new store
+        assert!(std::fs::write(dir.join("bad.wasm"), b"not a wasm file").is_ok());
+        let result = Store::create();
+        assert!(result.is_ok());
+        let mut store = result.unwrap();
+        let result = store.register_dir(&mut executor, &dir);
+        assert!(result.is_err());
+        match result.unwrap_err() {
+            WasmEdgeError::Store(StoreError::RegisterDir { registered, failed }) => {
+                assert_eq!(registered, ["fib1", "fib2"]);
+                assert_eq!(failed.len(), 1);
+                assert_eq!(failed[0].0, dir.join("bad.wasm"));
+            }
+            _ => panic!("unexpected error"),
+        }
+        assert_eq!(store.reg_module_len(), 2);
+
+        // a missing directory is an error
+        let result = store.register_dir(&mut executor, dir.join("missing"));
+        assert!(result.is_err());
+
+        assert!(std::fs::remove_dir_all(&dir).is_ok());
+    }
+
+    fn real_add(_caller: &Caller, inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
         if inputs.len() != 2 {
             return Err(1);
         }
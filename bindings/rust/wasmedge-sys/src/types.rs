@@ -468,8 +468,12 @@ impl WasmValue {
 
     /// Returns the function index.
     ///
-    /// If the [WasmValue] is a `NullRef`, then `None` is returned.
+    /// If the [WasmValue] is not of the `FuncRef` type, or is a `NullRef`, then `None` is returned.
     pub fn func_ref(&self) -> Option<Function> {
+        if self.ty != ValType::FuncRef {
+            return None;
+        }
+
         unsafe {
             match ffi::WasmEdge_ValueIsNullRef(self.ctx) {
                 true => None,
@@ -504,8 +508,13 @@ impl WasmValue {
 
     /// Returns the reference to an external object.
     ///
-    /// If the [WasmValue] is a `NullRef`, then `None` is returned.
+    /// If the [WasmValue] is not of the `ExternRef` type, for example, a `FuncRef` or a number, or is a `NullRef`, then
+    /// `None` is returned instead of reinterpreting the bits of the value as a pointer.
     pub fn extern_ref<T>(&self) -> Option<&T> {
+        if self.ty != ValType::ExternRef {
+            return None;
+        }
+
         unsafe {
             match ffi::WasmEdge_ValueIsNullRef(self.ctx) {
                 true => None,
@@ -561,7 +570,7 @@ impl From<ffi::WasmEdge_Value> for WasmValue {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{RefType, Table, TableType};
+    use crate::{Caller, FuncType, RefType, Table, TableType};
     use std::{
         sync::{Arc, Mutex},
         thread,
@@ -617,6 +626,40 @@ mod tests {
         assert_eq!(val1.to_i32(), val2.to_i32());
     }
 
+    #[test]
+    fn test_types_value_ref_type_check() {
+        // FuncRef
+        let result = FuncType::create([], []);
+        assert!(result.is_ok());
+        let func_ty = result.unwrap();
+        let result = Function::create(&func_ty, Box::new(real_nop), 0);
+        assert!(result.is_ok());
+        let mut func = result.unwrap();
+        let val_func_ref = WasmValue::from_func_ref(&mut func);
+        assert_eq!(val_func_ref.ty(), ValType::FuncRef);
+        assert!(val_func_ref.func_ref().is_some());
+        assert!(val_func_ref.extern_ref::<Table>().is_none());
+
+        // ExternRef
+        let result = TableType::create(RefType::FuncRef, 10..=20);
+        assert!(result.is_ok());
+        let ty = result.unwrap();
+        let result = Table::create(&ty);
+        assert!(result.is_ok());
+        let mut table = result.unwrap();
+        let val_extern_ref = WasmValue::from_extern_ref(&mut table);
+        assert!(val_extern_ref.extern_ref::<Table>().is_some());
+        assert!(val_extern_ref.func_ref().is_none());
+
+        // numbers
+        let val_i64 = WasmValue::from_i64(0x7FF8_0000_0000_0001);
+        assert!(val_i64.extern_ref::<Table>().is_none());
+        assert!(val_i64.func_ref().is_none());
+        let val_f64 = WasmValue::from_f64(f64::NAN);
+        assert!(val_f64.extern_ref::<Table>().is_none());
+        assert!(val_f64.func_ref().is_none());
+    }
+
     #[test]
     fn test_types_value_send() {
         // I32
@@ -822,4 +865,8 @@ mod tests {
 
         handle.join().unwrap();
     }
+
+    fn real_nop(_caller: &Caller, _inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+        Ok(vec![])
+    }
 }
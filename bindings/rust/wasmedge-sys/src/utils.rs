@@ -1,6 +1,6 @@
 //! Defines the versioning and logging functions.
 
-use crate::{ffi, WasmEdgeResult};
+use crate::{ffi, ImportObject, WasmEdgeResult};
 use std::{
     ffi::{CStr, CString},
     path::Path,
@@ -53,6 +53,39 @@ pub fn semv_version() -> String {
     )
 }
 
+/// Struct of the build information of the underlying WasmEdge library.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildInfo {
+    /// The full version of the WasmEdge library.
+    pub version: String,
+    /// Whether the AOT compiler is available.
+    pub has_aot: bool,
+    /// Whether the WASI host module is available.
+    pub has_wasi: bool,
+    /// Whether the wasmedge_process host module is available.
+    pub has_wasmedge_process: bool,
+}
+
+/// Returns the [BuildInfo] which reports the capabilities compiled in the WasmEdge library.
+///
+/// The WasmEdge C API does not expose the build options, so the capabilities are detected by probing. The AOT
+/// compiler is probed only if the `aot` feature of this crate is enabled, otherwise `has_aot` is `false`.
+pub fn build_info() -> BuildInfo {
+    #[cfg(feature = "aot")]
+    let has_aot = crate::Compiler::create(None).is_ok();
+    #[cfg(not(feature = "aot"))]
+    let has_aot = false;
+
+    BuildInfo {
+        version: full_version()
+            .map(String::from)
+            .unwrap_or_else(|_| semv_version()),
+        has_aot,
+        has_wasi: ImportObject::create_wasi(None, None, None).is_ok(),
+        has_wasmedge_process: ImportObject::create_wasmedge_process(None, false).is_ok(),
+    }
+}
+
 /// Logs the debug information.
 pub fn log_debug_info() {
     unsafe { ffi::WasmEdge_LogSetDebugLevel() }
@@ -62,3 +95,36 @@ pub fn log_debug_info() {
 pub fn log_error_info() {
     unsafe { ffi::WasmEdge_LogSetErrorLevel() }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_utils_build_info() {
+        let info = build_info();
+        assert!(!info.version.is_empty());
+        assert!(info.version.starts_with(&semv_version()));
+        assert!(info.has_wasi);
+        assert!(info.has_wasmedge_process);
+
+        // the reported AOT compiler is usable
+        #[cfg(feature = "aot")]
+        if info.has_aot {
+            let result = crate::Compiler::create(None);
+            assert!(result.is_ok());
+            let compiler = result.unwrap();
+            let in_path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+                .join("bindings/rust/wasmedge-sys/tests/data/fibonacci.wasm");
+            let out_path = std::env::temp_dir().join(format!(
+                "wasmedge_sys_test_utils_build_info_{}.wasm",
+                std::process::id()
+            ));
+            let result = compiler.compile(&in_path, &out_path);
+            assert!(result.is_ok());
+            assert!(std::fs::remove_file(out_path).is_ok());
+        }
+        #[cfg(not(feature = "aot"))]
+        assert!(!info.has_aot);
+    }
+}
@@ -5,13 +5,21 @@ use crate::{
     ffi,
     ffi::{WasmEdge_HostRegistration_Wasi, WasmEdge_HostRegistration_WasmEdge_Process},
     import_obj::{ImportObject, InnerImportObject},
-    instance::function::{FuncType, InnerFuncType},
-    statistics::{InnerStat, Statistics},
+    instance::{
+        function::{FuncType, InnerFuncType},
+        module::drop_host_states,
+    },
+    statistics::{InnerStat, Records, Statistics},
     store::{InnerStore, Store},
     types::WasmEdgeString,
     utils, Config, Module, WasmEdgeResult, WasmValue,
 };
-use std::{collections::HashMap, path::Path};
+use std::{
+    collections::HashMap,
+    path::Path,
+    sync::{Arc, Mutex},
+    time::Instant,
+};
 
 /// Struct of WasmEdge Vm.
 ///
@@ -19,7 +27,10 @@ use std::{collections::HashMap, path::Path};
 #[derive(Debug)]
 pub struct Vm {
     pub(crate) inner: InnerVm,
-    imports: HashMap<String, ImportObject>,
+    imports: HashMap<String, Arc<ImportObject>>,
+    records: Arc<Mutex<Records>>,
+    // whether the store is allocated by the Vm rather than given by the caller
+    owns_store: bool,
 }
 impl Vm {
     /// Creates a new [Vm] to be associated with the given [configuration](crate::Config) and [store](crate::Store).
@@ -34,6 +45,7 @@ impl Vm {
     ///
     /// If fail to create, then an error is returned.
     pub fn create(config: Option<Config>, store: Option<&mut Store>) -> WasmEdgeResult<Self> {
+        let owns_store = store.is_none();
         let ctx = match config {
             Some(mut config) => {
                 let vm_ctx = match store {
@@ -58,6 +70,8 @@ impl Vm {
             false => Ok(Self {
                 inner: InnerVm(ctx),
                 imports: HashMap::new(),
+                records: Arc::new(Mutex::new(Records::default())),
+                owns_store,
             }),
         }
     }
@@ -89,15 +103,16 @@ impl Vm {
     ) -> WasmEdgeResult<()> {
         let path = utils::path_to_cstring(path.as_ref())?;
         let mod_name: WasmEdgeString = mod_name.as_ref().into();
-        unsafe {
+        let start = Instant::now();
+        let result = unsafe {
             check(ffi::WasmEdge_VMRegisterModuleFromFile(
                 self.inner.0,
                 mod_name.as_raw(),
                 path.as_ptr(),
-            ))?
+            ))
         };
-
-        Ok(())
+        self.record_instantiate(start);
+        result
     }
 
     /// Consumes a given WasmEdge [ImportObject](crate::ImportObject) module to register and instantiate a WASM module
@@ -119,6 +134,34 @@ impl Vm {
     ///
     /// If fail to register the WASM module, then an error is returned.
     pub fn register_wasm_from_import(&mut self, import: ImportObject) -> WasmEdgeResult<()> {
+        self.register_wasm_from_shared_import(Arc::new(import))
+    }
+
+    /// Registers a WasmEdge [ImportObject](crate::ImportObject) module shared with other [Vm]s into the
+    /// [store](crate::Store) of the [Vm].
+    ///
+    /// The same [ImportObject](crate::ImportObject) module can be registered into multiple [Vm]s, for example, to
+    /// share a WASI module or a host memory among them. Each [Vm] holds a clone of the given `Arc`, so the underlying
+    /// context is freed only once, after the last [Vm] holding it is dropped.
+    ///
+    /// # Argument
+    ///
+    /// - `import` specifies the shared [ImportObject](crate::ImportObject) module to be registered.
+    ///
+    /// # Error
+    ///
+    /// If fail to register the WASM module, then an error is returned.
+    ///
+    /// # Notice
+    ///
+    /// The host instances in the [ImportObject](crate::ImportObject) module, such as [memories](crate::Memory) and
+    /// [globals](crate::Global), are not copied, so the [Vm]s sharing them see the changes made by each other. The
+    /// WasmEdge runtime does not synchronize the accesses to them, so running the [Vm]s sharing a mutable host
+    /// instance in different threads at the same time must be avoided.
+    pub fn register_wasm_from_shared_import(
+        &mut self,
+        import: Arc<ImportObject>,
+    ) -> WasmEdgeResult<()> {
         let io_name = import.name();
         if self.imports.contains_key(&io_name) {
             return Err(WasmEdgeError::Vm(VmError::DuplicateImportObject));
@@ -126,18 +169,21 @@ impl Vm {
             self.imports.insert(io_name.clone(), import);
         }
 
-        unsafe {
+        let import_ctx = self
+            .imports
+            .get(&io_name)
+            .ok_or(WasmEdgeError::Vm(VmError::NotFoundImportObject(io_name)))?
+            .inner
+            .0;
+        let start = Instant::now();
+        let result = unsafe {
             check(ffi::WasmEdge_VMRegisterModuleFromImport(
                 self.inner.0,
-                self.imports
-                    .get(&io_name)
-                    .ok_or(WasmEdgeError::Vm(VmError::NotFoundImportObject(io_name)))?
-                    .inner
-                    .0,
-            ))?;
-        }
-
-        Ok(())
+                import_ctx,
+            ))
+        };
+        self.record_instantiate(start);
+        result
     }
 
     /// Registers and instantiates a WASM module into the [store](crate::Store) of the [Vm] from a given WASM
@@ -166,16 +212,17 @@ impl Vm {
         buffer: &[u8],
     ) -> WasmEdgeResult<()> {
         let mod_name: WasmEdgeString = mod_name.as_ref().into();
-        unsafe {
+        let start = Instant::now();
+        let result = unsafe {
             check(ffi::WasmEdge_VMRegisterModuleFromBuffer(
                 self.inner.0,
                 mod_name.as_raw(),
                 buffer.as_ptr(),
                 buffer.len() as u32,
-            ))?;
-        }
-
-        Ok(())
+            ))
+        };
+        self.record_instantiate(start);
+        result
     }
 
     /// Consumes a given WasmEdge AST [Module](crate::Module) to register and instantiate a WASM module into the
@@ -205,13 +252,16 @@ impl Vm {
         mut module: Module,
     ) -> WasmEdgeResult<()> {
         let mod_name: WasmEdgeString = mod_name.as_ref().into();
-        unsafe {
+        let start = Instant::now();
+        let result = unsafe {
             check(ffi::WasmEdge_VMRegisterModuleFromASTModule(
                 self.inner.0,
                 mod_name.as_raw(),
                 module.inner.0,
-            ))?;
-        }
+            ))
+        };
+        self.record_instantiate(start);
+        result?;
         module.inner.0 = std::ptr::null_mut();
         Ok(())
     }
@@ -421,10 +471,18 @@ impl Vm {
     ///
     /// If fail to instantiate, then an error is returned.
     pub fn instantiate(&mut self) -> WasmEdgeResult<()> {
-        unsafe {
-            check(ffi::WasmEdge_VMInstantiate(self.inner.0))?;
-        }
-        Ok(())
+        let start = Instant::now();
+        let result = unsafe { check(ffi::WasmEdge_VMInstantiate(self.inner.0)) };
+        self.record_instantiate(start);
+        result
+    }
+
+    /// Records the time elapsed since `start` as the time spent in instantiating modules.
+    fn record_instantiate(&self, start: Instant) {
+        self.records
+            .lock()
+            .expect("[wasmedge-sys] try lock failed.")
+            .instantiate += start.elapsed();
     }
 
     /// Runs an exported WASM function by name. The WASM function is hosted by the anonymous [module](crate::Module) in
@@ -457,7 +515,8 @@ impl Vm {
         let mut returns = Vec::with_capacity(returns_len as usize);
 
         let func_name: WasmEdgeString = func_name.as_ref().into();
-        unsafe {
+        let start = Instant::now();
+        let result = unsafe {
             check(ffi::WasmEdge_VMExecute(
                 self.inner.0,
                 func_name.as_raw(),
@@ -465,9 +524,11 @@ impl Vm {
                 raw_params.len() as u32,
                 returns.as_mut_ptr(),
                 returns_len,
-            ))?;
-            returns.set_len(returns_len as usize);
-        }
+            ))
+        };
+        self.record_invoke(start, None);
+        result?;
+        unsafe { returns.set_len(returns_len as usize) };
 
         Ok(returns.into_iter().map(Into::into).collect::<Vec<_>>())
     }
@@ -503,24 +564,45 @@ impl Vm {
         let returns_len = unsafe { ffi::WasmEdge_FunctionTypeGetReturnsLength(func_type.inner.0) };
         let mut returns = Vec::with_capacity(returns_len as usize);
 
-        let mod_name: WasmEdgeString = mod_name.as_ref().into();
+        let raw_mod_name: WasmEdgeString = mod_name.as_ref().into();
         let func_name: WasmEdgeString = func_name.as_ref().into();
-        unsafe {
+        let start = Instant::now();
+        let result = unsafe {
             check(ffi::WasmEdge_VMExecuteRegistered(
                 self.inner.0,
-                mod_name.as_raw(),
+                raw_mod_name.as_raw(),
                 func_name.as_raw(),
                 raw_params.as_ptr(),
                 raw_params.len() as u32,
                 returns.as_mut_ptr(),
                 returns_len,
-            ))?;
-            returns.set_len(returns_len as usize);
-        }
+            ))
+        };
+        self.record_invoke(start, Some(mod_name.as_ref()));
+        result?;
+        unsafe { returns.set_len(returns_len as usize) };
 
         Ok(returns.into_iter().map(Into::into).collect::<Vec<_>>())
     }
 
+    /// Records the time elapsed since `start`, and the size of the memories of the invoked module if the tracking is
+    /// enabled by [Statistics::track_memory](crate::Statistics::track_memory).
+    fn record_invoke(&self, start: Instant, mod_name: Option<&str>) {
+        let elapsed = start.elapsed();
+        let mut records = self
+            .records
+            .lock()
+            .expect("[wasmedge-sys] try lock failed.");
+        records.invoke += elapsed;
+        if records.track_memory {
+            let pages = self
+                .store_mut()
+                .ok()
+                .and_then(|store| store.max_mem_pages(mod_name));
+            records.record_memory_pages(pages);
+        }
+    }
+
     /// Returns the function type of a WASM function by its name. The function is hosted in the anonymous [module](crate::Module) of the [Vm].
     ///
     /// # Argument
@@ -680,6 +762,7 @@ impl Vm {
             false => Ok(Statistics {
                 inner: InnerStat(stat_ctx),
                 registered: true,
+                records: Arc::clone(&self.records),
             }),
         }
     }
@@ -733,6 +816,11 @@ impl Vm {
 impl Drop for Vm {
     fn drop(&mut self) {
         if !self.inner.0.is_null() {
+            if self.owns_store {
+                if let Ok(store) = self.store_mut() {
+                    drop_host_states(&store);
+                }
+            }
             unsafe { ffi::WasmEdge_VMDelete(self.inner.0) };
         }
 
@@ -746,15 +834,304 @@ pub(crate) struct InnerVm(pub(crate) *mut ffi::WasmEdge_VMContext);
 unsafe impl Send for InnerVm {}
 unsafe impl Sync for InnerVm {}
 
+/// Struct of WasmEdge VmBuilder.
+///
+/// A [VmBuilder] collects the [configuration](crate::Config), the [store](crate::Store), the WASI settings and the
+/// [ImportObject](crate::ImportObject) modules used by a [Vm], and creates the [Vm] with all of them in one
+/// expression.
+///
+/// # Example
+///
+/// ```ignore
+/// let vm = VmBuilder::new()
+///     .config(config)
+///     .with_wasi(Some(vec!["app.wasm"]), None, Some(vec![".:."]))
+///     .add_import(import_obj)
+///     .build()?;
+/// ```
+#[derive(Debug, Default)]
+pub struct VmBuilder<'a> {
+    config: Option<Config>,
+    store: Option<&'a mut Store>,
+    wasi: Option<WasiArgs>,
+    imports: Vec<ImportObject>,
+}
+impl<'a> VmBuilder<'a> {
+    /// Creates a new [VmBuilder] without any configuration, store, WASI settings or import modules.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the [configuration](crate::Config) of the [Vm] to build.
+    ///
+    /// # Argument
+    ///
+    /// - `config` specifies the configuration for the new [Vm].
+    pub fn config(self, config: Config) -> Self {
+        Self {
+            config: Some(config),
+            ..self
+        }
+    }
+
+    /// Sets an external [store](crate::Store) used by the [Vm] to build.
+    ///
+    /// # Argument
+    ///
+    /// - `store` specifies the external WASM [store](crate::Store) used by the new [Vm].
+    pub fn store(self, store: &'a mut Store) -> Self {
+        Self {
+            store: Some(store),
+            ..self
+        }
+    }
+
+    /// Enables WASI support in the [Vm] to build, and sets the settings used to initialize the WASI
+    /// [ImportObject](crate::ImportObject) module.
+    ///
+    /// The guest inherits the stdio of the host process. See [create_wasi](crate::ImportObject::create_wasi) for the
+    /// limitations of the WASI module.
+    ///
+    /// # Arguments
+    ///
+    /// - `args` specifies the commandline arguments. The first argument is the program name.
+    ///
+    /// - `envs` specifies the environment variables in the format `ENV_VAR_NAME=VALUE`.
+    ///
+    /// - `preopens` specifies the directories to pre-open. The required format is `DIR1:DIR2`.
+    pub fn with_wasi(
+        self,
+        args: Option<Vec<&str>>,
+        envs: Option<Vec<&str>>,
+        preopens: Option<Vec<&str>>,
+    ) -> Self {
+        let to_owned = |v: Option<Vec<&str>>| {
+            v.map(|v| v.into_iter().map(String::from).collect::<Vec<String>>())
+        };
+        Self {
+            wasi: Some(WasiArgs {
+                args: to_owned(args),
+                envs: to_owned(envs),
+                preopens: to_owned(preopens),
+            }),
+            ..self
+        }
+    }
+
+    /// Adds an [ImportObject](crate::ImportObject) module to be registered into the [Vm] to build.
+    ///
+    /// # Argument
+    ///
+    /// - `import` specifies the [ImportObject](crate::ImportObject) module to be registered.
+    pub fn add_import(mut self, import: ImportObject) -> Self {
+        self.imports.push(import);
+        self
+    }
+
+    /// Creates a new [Vm] from the settings of the [VmBuilder].
+    ///
+    /// # Error
+    ///
+    /// If two of the added [ImportObject](crate::ImportObject) modules have the same name, or fail to create the
+    /// [Vm], or fail to register the [ImportObject](crate::ImportObject) modules, then an error is returned.
+    pub fn build(self) -> WasmEdgeResult<Vm> {
+        // check the names of the import modules before creating the Vm
+        let mut names = std::collections::HashSet::new();
+        if self.wasi.is_some() {
+            names.insert(String::from("wasi_snapshot_preview1"));
+        }
+        for import in self.imports.iter() {
+            if !names.insert(import.name()) {
+                return Err(WasmEdgeError::Vm(VmError::DuplicateImportObject));
+            }
+        }
+
+        // enable wasi in the config
+        let config = match self.wasi {
+            Some(_) => {
+                let mut config = match self.config {
+                    Some(config) => config,
+                    None => Config::create()?,
+                };
+                config.wasi(true);
+                Some(config)
+            }
+            None => self.config,
+        };
+
+        let mut vm = Vm::create(config, self.store)?;
+
+        // initialize the wasi module
+        if let Some(wasi) = self.wasi {
+            let as_strs = |v: &Option<Vec<String>>| {
+                v.as_ref()
+                    .map(|v| v.iter().map(|s| s.as_str()).collect::<Vec<&str>>())
+            };
+            let mut wasi_import = vm.wasi_import_module_mut()?;
+            wasi_import.init_wasi(
+                as_strs(&wasi.args),
+                as_strs(&wasi.envs),
+                as_strs(&wasi.preopens),
+            );
+        }
+
+        // register the import modules
+        for import in self.imports {
+            vm.register_wasm_from_import(import)?;
+        }
+
+        Ok(vm)
+    }
+}
+
+#[derive(Debug, Default)]
+struct WasiArgs {
+    args: Option<Vec<String>>,
+    envs: Option<Vec<String>>,
+    preopens: Option<Vec<String>>,
+}
+
+/// Struct of WasmEdge AsyncVmPool.
+///
+/// An [AsyncVmPool] holds a set of [Vm]s, and bounds the number of the invocations running at the same time. Each
+/// invocation takes a [Vm] from the pool exclusively, and runs on the blocking thread pool of tokio, so the async
+/// runtime is not blocked by the running WASM functions.
+///
+/// # Notice
+///
+/// The [Vm]s in the pool are used interchangeably, so all of them should register the same modules.
+#[cfg(feature = "async")]
+#[derive(Debug)]
+pub struct AsyncVmPool {
+    vms: Arc<Mutex<Vec<Vm>>>,
+    semaphore: Arc<tokio::sync::Semaphore>,
+}
+#[cfg(feature = "async")]
+impl AsyncVmPool {
+    /// Creates a new [AsyncVmPool] with the given [Vm]s and concurrency limit.
+    ///
+    /// # Arguments
+    ///
+    /// - `vms` specifies the [Vm]s in the pool.
+    ///
+    /// - `limit` specifies the maximum number of the invocations running at the same time. If it is larger than the
+    /// number of the [Vm]s, then the number of the [Vm]s is used.
+    ///
+    /// # Error
+    ///
+    /// If `vms` is empty or `limit` is zero, then an error is returned.
+    pub fn create(vms: Vec<Vm>, limit: usize) -> WasmEdgeResult<Self> {
+        if vms.is_empty() || limit == 0 {
+            return Err(WasmEdgeError::Vm(VmError::EmptyPool));
+        }
+
+        let permits = limit.min(vms.len());
+        Ok(Self {
+            vms: Arc::new(Mutex::new(vms)),
+            semaphore: Arc::new(tokio::sync::Semaphore::new(permits)),
+        })
+    }
+
+    /// Returns the number of the invocations which can start without waiting.
+    pub fn available(&self) -> usize {
+        self.semaphore.available_permits()
+    }
+
+    /// Runs a function of a registered module in a [Vm] of the pool, and returns the results.
+    ///
+    /// If the number of the running invocations reaches the concurrency limit, then it waits until one of them
+    /// finishes. If the returned future is dropped after the function starts, for example, by a timeout, then the
+    /// function keeps running to completion, and the [Vm] is returned to the pool afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// - `mod_name` specifies the name of the registered module.
+    ///
+    /// - `func_name` specifies the name of the target function.
+    ///
+    /// - `params` specifies the argument values for the target function.
+    ///
+    /// # Error
+    ///
+    /// If fail to run the function, then an error is returned.
+    pub async fn run(
+        &self,
+        mod_name: impl AsRef<str>,
+        func_name: impl AsRef<str>,
+        params: impl IntoIterator<Item = WasmValue>,
+    ) -> WasmEdgeResult<Vec<WasmValue>> {
+        let permit = Arc::clone(&self.semaphore)
+            .acquire_owned()
+            .await
+            .expect("[wasmedge-sys] the semaphore of the Vm pool is closed.");
+        let vm = self
+            .vms
+            .lock()
+            .expect("[wasmedge-sys] try lock failed.")
+            .pop()
+            .expect("[wasmedge-sys] no Vm is available in the pool.");
+        // the Vm and the permit are returned by the blocking task, even if this future is dropped before it finishes
+        let pooled = PooledVm {
+            vm: Some(vm),
+            vms: Arc::clone(&self.vms),
+            permit: Some(permit),
+        };
+
+        let mod_name = mod_name.as_ref().to_string();
+        let func_name = func_name.as_ref().to_string();
+        let params = params.into_iter().collect::<Vec<_>>();
+        let joined = tokio::task::spawn_blocking(move || {
+            let vm = pooled.vm.as_ref().expect("[wasmedge-sys] the Vm is taken.");
+            vm.run_registered_function(mod_name, func_name, params)
+        })
+        .await;
+
+        match joined {
+            Ok(result) => result,
+            Err(e) => std::panic::resume_unwind(e.into_panic()),
+        }
+    }
+}
+
+/// A [Vm] taken from an [AsyncVmPool], which is returned to the pool together with its permit when dropped.
+#[cfg(feature = "async")]
+struct PooledVm {
+    vm: Option<Vm>,
+    vms: Arc<Mutex<Vec<Vm>>>,
+    permit: Option<tokio::sync::OwnedSemaphorePermit>,
+}
+#[cfg(feature = "async")]
+impl Drop for PooledVm {
+    fn drop(&mut self) {
+        match (self.vm.take(), self.permit.take()) {
+            (Some(vm), Some(permit)) if !std::thread::panicking() => {
+                self.vms
+                    .lock()
+                    .expect("[wasmedge-sys] try lock failed.")
+                    .push(vm);
+                drop(permit);
+            }
+            // the Vm is lost, so the permit is not returned to keep the permits matching the Vms
+            (_, Some(permit)) => permit.forget(),
+            _ => {}
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Vm;
+    #[cfg(feature = "async")]
+    use super::AsyncVmPool;
+    use super::{Vm, VmBuilder};
+    #[cfg(feature = "async")]
+    use crate::HostFunc;
     use crate::{
         error::{
             CoreCommonError, CoreError, CoreExecutionError, CoreInstantiationError, CoreLoadError,
             StoreError, VmError, WasmEdgeError,
         },
-        Config, FuncType, Function, ImportObject, Loader, Module, Store, ValType, WasmValue,
+        Caller, Config, FuncType, Function, ImportObject, Loader, Module, Store, ValType,
+        WasmValue,
     };
     use std::{
         sync::{Arc, Mutex},
@@ -1336,6 +1713,64 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_vm_register_wasm_from_shared_import() {
+        // create ImportObject module
+        let result = ImportObject::create("extern");
+        assert!(result.is_ok());
+        let mut import_obj = result.unwrap();
+
+        // add host function
+        let result = FuncType::create(vec![ValType::I32; 2], vec![ValType::I32]);
+        assert!(result.is_ok());
+        let func_ty = result.unwrap();
+        let result = Function::create(&func_ty, Box::new(real_add), 0);
+        assert!(result.is_ok());
+        let host_func = result.unwrap();
+        import_obj.add_func("add", host_func);
+        let import_obj = Arc::new(import_obj);
+
+        // register the import_obj module into two Vm contexts
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm1 = result.unwrap();
+        let result = vm1.register_wasm_from_shared_import(Arc::clone(&import_obj));
+        assert!(result.is_ok());
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm2 = result.unwrap();
+        let result = vm2.register_wasm_from_shared_import(Arc::clone(&import_obj));
+        assert!(result.is_ok());
+        assert_eq!(Arc::strong_count(&import_obj), 3);
+
+        // invoke the host function through both Vm contexts
+        let result = vm1.run_registered_function(
+            "extern",
+            "add",
+            [WasmValue::from_i32(1), WasmValue::from_i32(2)],
+        );
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 3);
+        let result = vm2.run_registered_function(
+            "extern",
+            "add",
+            [WasmValue::from_i32(3), WasmValue::from_i32(4)],
+        );
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 7);
+
+        // the import_obj module is still alive in vm2 after vm1 is dropped
+        drop(vm1);
+        assert_eq!(Arc::strong_count(&import_obj), 2);
+        let result = vm2.run_registered_function(
+            "extern",
+            "add",
+            [WasmValue::from_i32(5), WasmValue::from_i32(6)],
+        );
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap()[0].to_i32(), 11);
+    }
+
     #[test]
     fn test_vm_register_wasm_from_buffer() {
         // create a Config context
@@ -1833,6 +2268,181 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_vm_builder() {
+        // create ImportObject module
+        let result = ImportObject::create("extern");
+        assert!(result.is_ok());
+        let mut import_obj = result.unwrap();
+        let result = FuncType::create(vec![ValType::I32; 2], vec![ValType::I32]);
+        assert!(result.is_ok());
+        let func_ty = result.unwrap();
+        let result = Function::create(&func_ty, Box::new(real_add), 0);
+        assert!(result.is_ok());
+        let host_func = result.unwrap();
+        import_obj.add_func("add", host_func);
+
+        // build a Vm with WASI and the import_obj module
+        let result = VmBuilder::new()
+            .with_wasi(Some(vec!["prog", "a"]), None, None)
+            .add_import(import_obj)
+            .build();
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+
+        // (module
+        //   (import "wasi_snapshot_preview1" "args_sizes_get"
+        //     (func $args_sizes_get (param i32 i32) (result i32)))
+        //   (import "extern" "add" (func $add (param i32 i32) (result i32)))
+        //   (memory (export "memory") 1)
+        //   (func (export "run") (result i32)
+        //     (drop (call $args_sizes_get (i32.const 0) (i32.const 4)))
+        //     (call $add (i32.load (i32.const 0)) (i32.const 10))))
+        let wasm = [
+            0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, 0x01, 0x0b, 0x02, 0x60, 0x02, 0x7f,
+            0x7f, 0x01, 0x7f, 0x60, 0x00, 0x01, 0x7f, 0x02, 0x36, 0x02, 0x16, 0x77, 0x61, 0x73,
+            0x69, 0x5f, 0x73, 0x6e, 0x61, 0x70, 0x73, 0x68, 0x6f, 0x74, 0x5f, 0x70, 0x72, 0x65,
+            0x76, 0x69, 0x65, 0x77, 0x31, 0x0e, 0x61, 0x72, 0x67, 0x73, 0x5f, 0x73, 0x69, 0x7a,
+            0x65, 0x73, 0x5f, 0x67, 0x65, 0x74, 0x00, 0x00, 0x06, 0x65, 0x78, 0x74, 0x65, 0x72,
+            0x6e, 0x03, 0x61, 0x64, 0x64, 0x00, 0x00, 0x03, 0x02, 0x01, 0x01, 0x05, 0x03, 0x01,
+            0x00, 0x01, 0x07, 0x10, 0x02, 0x06, 0x6d, 0x65, 0x6d, 0x6f, 0x72, 0x79, 0x02, 0x00,
+            0x03, 0x72, 0x75, 0x6e, 0x00, 0x02, 0x0a, 0x14, 0x01, 0x12, 0x00, 0x41, 0x00, 0x41,
+            0x04, 0x10, 0x00, 0x1a, 0x41, 0x00, 0x28, 0x02, 0x00, 0x41, 0x0a, 0x10, 0x01, 0x0b,
+        ];
+
+        // the module reads the number of WASI arguments and adds 10 by the host function
+        let result = vm.run_wasm_from_buffer(&wasm, "run", []);
+        assert!(result.is_ok());
+        let returns = result.unwrap();
+        assert_eq!(returns[0].to_i32(), 12);
+
+        // build a Vm with two import modules of the same name
+        let result = ImportObject::create("extern");
+        assert!(result.is_ok());
+        let import_obj1 = result.unwrap();
+        let result = ImportObject::create("extern");
+        assert!(result.is_ok());
+        let import_obj2 = result.unwrap();
+        let result = VmBuilder::new()
+            .add_import(import_obj1)
+            .add_import(import_obj2)
+            .build();
+        assert!(result.is_err());
+        assert_eq!(
+            result.unwrap_err(),
+            WasmEdgeError::Vm(VmError::DuplicateImportObject)
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_vm_async_pool() {
+        use std::{
+            sync::atomic::{AtomicUsize, Ordering},
+            time::{Duration, Instant},
+        };
+
+        let running = Arc::new(AtomicUsize::new(0));
+        let max_running = Arc::new(AtomicUsize::new(0));
+
+        // create three Vms, each registering a host function which sleeps for a while
+        let mut vms = Vec::new();
+        for _ in 0..3 {
+            let running = Arc::clone(&running);
+            let max_running = Arc::clone(&max_running);
+            vms.push(create_pool_vm(Box::new(
+                move |_caller: &Caller, _inputs: Vec<WasmValue>| {
+                    let n = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_running.fetch_max(n, Ordering::SeqCst);
+                    thread::sleep(Duration::from_millis(200));
+                    running.fetch_sub(1, Ordering::SeqCst);
+                    Ok(vec![WasmValue::from_i32(n as i32)])
+                },
+            )));
+        }
+
+        // create a pool with the concurrency limit of 2
+        let result = AsyncVmPool::create(vms, 2);
+        assert!(result.is_ok());
+        let pool = result.unwrap();
+        assert_eq!(pool.available(), 2);
+
+        // the third call waits until one of the first two calls finishes
+        let start = Instant::now();
+        let (r1, r2, r3) = tokio::join!(
+            pool.run("extern", "sleep", []),
+            pool.run("extern", "sleep", []),
+            pool.run("extern", "sleep", []),
+        );
+        assert!(r1.is_ok());
+        assert!(r2.is_ok());
+        assert!(r3.is_ok());
+        assert!(start.elapsed() >= Duration::from_millis(400));
+        assert_eq!(max_running.load(Ordering::SeqCst), 2);
+        assert_eq!(pool.available(), 2);
+
+        // create a pool without Vms
+        let result = AsyncVmPool::create(vec![], 2);
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err(), WasmEdgeError::Vm(VmError::EmptyPool));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_vm_async_pool_cancel() {
+        use std::time::Duration;
+
+        // create two Vms, each registering a host function which sleeps for a while
+        let mut vms = Vec::new();
+        for _ in 0..2 {
+            vms.push(create_pool_vm(Box::new(
+                |_caller: &Caller, _inputs: Vec<WasmValue>| {
+                    thread::sleep(Duration::from_millis(200));
+                    Ok(vec![WasmValue::from_i32(1)])
+                },
+            )));
+        }
+        let result = AsyncVmPool::create(vms, 2);
+        assert!(result.is_ok());
+        let pool = result.unwrap();
+
+        // cancel a call while the function is still running
+        let result =
+            tokio::time::timeout(Duration::from_millis(50), pool.run("extern", "sleep", [])).await;
+        assert!(result.is_err());
+
+        // the Vm and the permit are returned once the function finishes, so the next calls can use all the Vms
+        let (r1, r2, r3) = tokio::join!(
+            pool.run("extern", "sleep", []),
+            pool.run("extern", "sleep", []),
+            pool.run("extern", "sleep", []),
+        );
+        assert!(r1.is_ok());
+        assert!(r2.is_ok());
+        assert!(r3.is_ok());
+        assert_eq!(pool.available(), 2);
+    }
+
+    #[cfg(feature = "async")]
+    fn create_pool_vm(host_func: HostFunc) -> Vm {
+        let result = ImportObject::create("extern");
+        assert!(result.is_ok());
+        let mut import_obj = result.unwrap();
+        let result = FuncType::create([], [ValType::I32]);
+        assert!(result.is_ok());
+        let func_ty = result.unwrap();
+        let result = Function::create(&func_ty, host_func, 0);
+        assert!(result.is_ok());
+        import_obj.add_func("sleep", result.unwrap());
+
+        let result = Vm::create(None, None);
+        assert!(result.is_ok());
+        let mut vm = result.unwrap();
+        let result = vm.register_wasm_from_import(import_obj);
+        assert!(result.is_ok());
+        vm
+    }
+
     fn load_fib_module() -> Module {
         // load a module
         let result = Loader::create(None);
@@ -1845,7 +2455,7 @@ mod tests {
         result.unwrap()
     }
 
-    fn real_add(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+    fn real_add(_caller: &Caller, inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
         if inputs.len() != 2 {
             return Err(1);
         }
@@ -1,4 +1,4 @@
-use wasmedge_sys::{FuncType, Function, ImportObject, ValType, WasmValue};
+use wasmedge_sys::{Caller, FuncType, Function, ImportObject, ValType, WasmValue};
 
 pub fn create_extern_module(name: impl AsRef<str>) -> ImportObject {
     // create an ImportObj module
@@ -57,7 +57,7 @@ pub fn create_extern_module(name: impl AsRef<str>) -> ImportObject {
     import_obj
 }
 
-fn _real_add(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+fn _real_add(_caller: &Caller, inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
     if inputs.len() != 2 {
         return Err(1);
     }
@@ -83,7 +83,7 @@ fn _real_add(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
     Ok(vec![WasmValue::from_i32(c)])
 }
 
-fn extern_add(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+fn extern_add(_caller: &Caller, inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
     let val1 = if inputs[0].ty() == ValType::ExternRef {
         inputs[0]
     } else {
@@ -102,7 +102,7 @@ fn extern_add(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
     Ok(vec![WasmValue::from_i32(val1 + val2)])
 }
 
-fn extern_sub(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+fn extern_sub(_caller: &Caller, inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
     let val1 = if inputs[0].ty() == ValType::ExternRef {
         inputs[0]
     } else {
@@ -122,7 +122,7 @@ fn extern_sub(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
     Ok(vec![WasmValue::from_i32(val1 - val2)])
 }
 
-fn extern_mul(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+fn extern_mul(_caller: &Caller, inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
     let val1 = if inputs[0].ty() == ValType::ExternRef {
         inputs[0]
     } else {
@@ -141,7 +141,7 @@ fn extern_mul(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
     Ok(vec![WasmValue::from_i32(val1 * val2)])
 }
 
-fn extern_div(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+fn extern_div(_caller: &Caller, inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
     let val1 = if inputs[0].ty() == ValType::ExternRef {
         inputs[0]
     } else {
@@ -160,10 +160,10 @@ fn extern_div(inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
     Ok(vec![WasmValue::from_i32(val1 / val2)])
 }
 
-fn extern_term(_inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+fn extern_term(_caller: &Caller, _inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
     Ok(vec![WasmValue::from_i32(1234)])
 }
 
-fn extern_fail(_inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
+fn extern_fail(_caller: &Caller, _inputs: Vec<WasmValue>) -> Result<Vec<WasmValue>, u8> {
     Err(0x02)
 }
@@ -1,4 +1,5 @@
 mod common;
+use std::time::Duration;
 use wasmedge_sys::{
     error::{CoreError, CoreExecutionError, StoreError, WasmEdgeError},
     Config, Executor, Loader, Statistics, Store, Validator, WasmValue,
@@ -265,4 +266,75 @@ fn test_executor_with_statistics() {
         result.unwrap_err(),
         WasmEdgeError::Core(CoreError::Execution(CoreExecutionError::ExecutionFailed))
     );
+
+    // check the time spent in instantiation and invocation
+    assert!(stat.instantiate_time() > Duration::ZERO);
+    assert!(stat.invoke_time() > Duration::ZERO);
+}
+
+#[test]
+fn test_executor_with_statistics_per_module() {
+    // create a Statistics context with its own cost table for each module
+    let result = Statistics::create();
+    assert!(result.is_ok());
+    let mut stat1 = result.unwrap();
+    stat1.set_cost_table(vec![1u64; 512]);
+    stat1.set_cost_limit(u64::MAX);
+    let result = Statistics::create();
+    assert!(result.is_ok());
+    let mut stat2 = result.unwrap();
+    stat2.set_cost_table(vec![3u64; 512]);
+    stat2.set_cost_limit(u64::MAX);
+
+    // create an Executor context for each module
+    let result = Config::create();
+    assert!(result.is_ok());
+    let mut config = result.unwrap();
+    config.measure_cost(true);
+    let result = Executor::create(Some(config), Some(&mut stat1));
+    assert!(result.is_ok());
+    let mut executor1 = result.unwrap();
+    let result = Config::create();
+    assert!(result.is_ok());
+    let mut config = result.unwrap();
+    config.measure_cost(true);
+    let result = Executor::create(Some(config), Some(&mut stat2));
+    assert!(result.is_ok());
+    let mut executor2 = result.unwrap();
+
+    // load and validate the module
+    let result = Loader::create(None);
+    assert!(result.is_ok());
+    let loader = result.unwrap();
+    let path = std::path::PathBuf::from(env!("WASMEDGE_DIR"))
+        .join("bindings/rust/wasmedge-sys/tests/data/fibonacci.wasm");
+    let result = loader.from_file(path);
+    assert!(result.is_ok());
+    let module = result.unwrap();
+    let result = Validator::create(None);
+    assert!(result.is_ok());
+    let validator = result.unwrap();
+    let result = validator.validate(&module);
+    assert!(result.is_ok());
+
+    // register the module twice into the same store, each through its own executor
+    let result = Store::create();
+    assert!(result.is_ok());
+    let mut store = result.unwrap();
+    let result = executor1.register_named_module(&mut store, &module, "tenant1");
+    assert!(result.is_ok());
+    let result = executor2.register_named_module(&mut store, &module, "tenant2");
+    assert!(result.is_ok());
+
+    // run the same workload in both modules
+    let result =
+        executor1.run_func_registered(&mut store, "tenant1", "fib", [WasmValue::from_i32(10)]);
+    assert!(result.is_ok());
+    let result =
+        executor2.run_func_registered(&mut store, "tenant2", "fib", [WasmValue::from_i32(10)]);
+    assert!(result.is_ok());
+
+    // check the per-module cost
+    assert!(stat1.cost_in_total() > 0);
+    assert_eq!(stat2.cost_in_total(), 3 * stat1.cost_in_total());
 }